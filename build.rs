@@ -0,0 +1,585 @@
+//! Generates the `Map` enum, hex enums and their `Location` implementations from the WarAPI static map
+//! dumps in `data/maps/`, which is included into `src/map/mod.rs`
+//!
+//! Each dump is made up of the `/worldconquest/maps` response under `maps` and each
+//! `/worldconquest/maps/{hex}/static` response under `static`, keyed by hex; see `scripts/maphexes.py`
+//! to add one after a game update. Dumps are named after the first war they apply to, e.g.
+//! `data/maps/83.json`, and every location from every dump is generated so that older battles can still
+//! be loaded once a town is removed, with a bitmask of which dumps each location and hex is in. As the
+//! api doesn't provide where hexes sit on the world map, the axial grid position of each hex is kept by
+//! hand in `data/grid.json`
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::{env, fs, path::Path};
+
+/// Directory of the checked-in static map dumps, one per map version
+const DUMP_DIR: &str = "data/maps";
+
+/// Directory of made-up dumps which add and remove hexes and locations between versions, generated into
+/// `fixture_map.rs` for testing versioning
+const FIXTURE_DIR: &str = "data/fixtures/maps";
+
+/// Location of the hand-kept axial grid positions for each hex
+const GRID_PATH: &str = "data/grid.json";
+
+/// Ordinal words used to turn locations starting with a number into valid identifiers
+const ORDINALS: [(&str, &str); 20] = [
+    ("1st", "First"),
+    ("2nd", "Second"),
+    ("3rd", "Third"),
+    ("4th", "Fourth"),
+    ("5th", "Fifth"),
+    ("6th", "Sixth"),
+    ("7th", "Seventh"),
+    ("8th", "Eighth"),
+    ("9th", "Ninth"),
+    ("10th", "Tenth"),
+    ("11th", "Eleventh"),
+    ("12th", "Twelfth"),
+    ("13th", "Thirteenth"),
+    ("14th", "Fourteenth"),
+    ("15th", "Fifteenth"),
+    ("16th", "Sixteenth"),
+    ("17th", "Seventeenth"),
+    ("18th", "Eighteenth"),
+    ("19th", "Nineteenth"),
+    ("20th", "Twentieth"),
+];
+
+#[derive(Deserialize)]
+struct Dump {
+    maps: Vec<String>,
+    #[serde(rename = "static")]
+    statics: HashMap<String, Static>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Static {
+    map_text_items: Vec<TextItem>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TextItem {
+    text: String,
+    x: f64,
+    y: f64,
+    map_marker_type: String,
+}
+
+/// Single hex ready to be generated
+struct Hex {
+    /// Full api name such as `StonecradleHex`
+    pos: String,
+    /// Enum and api-centric name such as `Stonecradle`
+    name: String,
+    /// Axial `q` and `r` grid position of this hex on the world map
+    axial: (i32, i32),
+    locations: Vec<HexLocation>,
+    /// Bitmask of the map versions this hex is in
+    versions: u64,
+}
+
+/// Single location within a hex ready to be generated
+struct HexLocation {
+    variant: String,
+    name_api: String,
+    name_friendly: String,
+    is_major: bool,
+    coords: (f64, f64),
+    /// Bitmask of the map versions this location is in
+    versions: u64,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", DUMP_DIR);
+    println!("cargo:rerun-if-changed={}", FIXTURE_DIR);
+    println!("cargo:rerun-if-changed={}", GRID_PATH);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let grid: HashMap<String, (i32, i32)> = serde_json::from_str(
+        &fs::read_to_string(GRID_PATH).expect("Couldn't read hex grid positions"),
+    )
+    .expect("Couldn't parse hex grid positions");
+    gen_file(DUMP_DIR, &grid, "map.rs");
+    gen_file(FIXTURE_DIR, &grid, "fixture_map.rs");
+}
+
+/// Generates the map from every dump within a directory, writing it to a file of the output directory
+fn gen_file(dump_dir: &str, grid: &HashMap<String, (i32, i32)>, file_name: &str) {
+    let versions = read_versions(dump_dir);
+    let hexes = merge_versions(
+        versions
+            .iter()
+            .map(|(_, dump)| {
+                dump.maps
+                    .iter()
+                    .map(|pos| hex_from_static(pos, &dump.statics, grid))
+                    .collect()
+            })
+            .collect(),
+    );
+    let wars: Vec<i64> = versions.iter().map(|(war_num, _)| *war_num).collect();
+
+    let mut out = String::new();
+    gen_map(&mut out, &hexes);
+    gen_hex_list(&mut out, &hexes);
+    gen_versions(&mut out, &hexes, &wars);
+    for hex in &hexes {
+        gen_hex(&mut out, hex);
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join(file_name);
+    fs::write(out_path, out).expect("Couldn't write generated map");
+}
+
+/// Reads every static map dump within a directory along with the first war number it applies to,
+/// oldest first
+fn read_versions(dump_dir: &str) -> Vec<(i64, Dump)> {
+    let mut versions: Vec<(i64, Dump)> = fs::read_dir(dump_dir)
+        .expect("Couldn't read static map dumps")
+        .map(|entry| entry.expect("Couldn't read static map dump").path())
+        .filter(|path| path.extension() == Some("json".as_ref()))
+        .map(|path| {
+            let war_num = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse().ok())
+                .unwrap_or_else(|| panic!("Static map dump {:?} isn't named by war", path));
+            let dump = serde_json::from_str(
+                &fs::read_to_string(&path).expect("Couldn't read static map dump"),
+            )
+            .unwrap_or_else(|err| panic!("Couldn't parse static map dump {:?}, {}", path, err));
+            (war_num, dump)
+        })
+        .collect();
+    assert!(!versions.is_empty(), "No static map dumps in {}", dump_dir);
+    assert!(
+        versions.len() <= 64,
+        "Too many map versions to fit a bitmask"
+    );
+    versions.sort_by_key(|(war_num, _)| *war_num);
+    versions
+}
+
+/// Merges the hexes of every map version, oldest first, into one list
+///
+/// Hexes and locations keep the order and details of the newest version they're in, with those since
+/// removed coming after
+fn merge_versions(versions: Vec<Vec<Hex>>) -> Vec<Hex> {
+    let mut merged: Vec<Hex> = vec![];
+    for (ind, hexes) in versions.into_iter().enumerate().rev() {
+        let bit = 1 << ind;
+        for hex in hexes {
+            let pos = match merged.iter().position(|other| other.name == hex.name) {
+                Some(pos) => pos,
+                None => {
+                    merged.push(Hex {
+                        locations: vec![],
+                        ..hex
+                    });
+                    merged.len() - 1
+                }
+            };
+            let existing = &mut merged[pos];
+            existing.versions |= bit;
+
+            for loc in hex.locations {
+                match existing
+                    .locations
+                    .iter_mut()
+                    .find(|other| other.variant == loc.variant)
+                {
+                    Some(other) => other.versions |= bit,
+                    None => existing.locations.push(HexLocation {
+                        versions: bit,
+                        ..loc
+                    }),
+                }
+            }
+        }
+    }
+    merged
+}
+
+/// Converts the static details of a hex into its generated form
+fn hex_from_static(
+    pos: &str,
+    statics: &HashMap<String, Static>,
+    grid: &HashMap<String, (i32, i32)>,
+) -> Hex {
+    let details = statics
+        .get(pos)
+        .unwrap_or_else(|| panic!("No static details for hex '{}'", pos));
+    let axial = *grid
+        .get(pos)
+        .unwrap_or_else(|| panic!("No grid position for hex '{}' in {}", pos, GRID_PATH));
+    let locations = details
+        .map_text_items
+        .iter()
+        .map(|item| {
+            let name_api: String = item
+                .text
+                .chars()
+                .filter(|c| *c != ' ' && *c != '\'')
+                .collect();
+            HexLocation {
+                variant: variant_name(&name_api),
+                name_api,
+                name_friendly: item.text.clone(),
+                is_major: item.map_marker_type.eq_ignore_ascii_case("major"),
+                coords: (item.x, item.y),
+                versions: 0,
+            }
+        })
+        .collect();
+
+    Hex {
+        pos: pos.to_string(),
+        // trims `Hex` from names, `MarbanHollow` becoming `MarbanHol` is kept for compatibility
+        name: pos[..pos.len() - 3].to_string(),
+        axial,
+        locations,
+        versions: 0,
+    }
+}
+
+/// Makes a valid identifier from an api-centric name, spelling out leading ordinals
+fn variant_name(name_api: &str) -> String {
+    if !name_api.starts_with(|c: char| c.is_ascii_digit()) {
+        return name_api.to_string();
+    }
+    ORDINALS
+        .iter()
+        .rev()
+        .find_map(|(num, word)| {
+            name_api
+                .strip_prefix(num)
+                .map(|rest| format!("{}{}", word, rest))
+        })
+        .unwrap_or_else(|| panic!("Can't make an identifier for location '{}'", name_api))
+}
+
+/// Generates the top-level `Map` enum and its delegating implementations
+fn gen_map(out: &mut String, hexes: &[Hex]) {
+    out.push_str(
+        "/// Rich location marker for each hex and then location of a map
+///
+/// Converts to and from the canonical `hex:location` string form using [fmt::Display] and [FromStr],
+/// which is also how it's (de)serialized
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Map {\n",
+    );
+    for hex in hexes {
+        writeln!(out, "    {0}({0}),", hex.name).unwrap();
+    }
+    out.push_str("}\n\nimpl Location for Map {\n");
+
+    out.push_str(
+        "    /// Gets map location from the full `hex` and `location` api-centric name, e.g. `Stonecradle:TheCord`
+    fn from_name(name: &str) -> Result<Self, LocationError> {
+        let (hex, location) = name
+            .split_once(NAME_SEP)
+            .ok_or_else(|| LocationError::Malformed(name.to_string()))?;
+        match hex {\n",
+    );
+    for hex in hexes {
+        writeln!(
+            out,
+            "            \"{0}\" => {0}::from_name(location).map(Map::{0}),",
+            hex.name
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "            _ => Err(LocationError::UnknownHex(hex.to_string())),\n        }\n    }\n",
+    );
+
+    for (func, ret) in [
+        ("is_major", "bool"),
+        ("coords", "(f64, f64)"),
+        ("name_api", "(&str, &str)"),
+        ("name_friendly", "(&str, &str)"),
+    ] {
+        writeln!(
+            out,
+            "\n    fn {}(&self) -> {} {{\n        match self {{",
+            func, ret
+        )
+        .unwrap();
+        for hex in hexes {
+            writeln!(out, "            Map::{}(val) => val.{}(),", hex.name, func).unwrap();
+        }
+        out.push_str("        }\n    }\n");
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl_name_conversions!(\n");
+    let names: Vec<_> = hexes
+        .iter()
+        .map(|hex| format!("    {}", hex.name))
+        .collect();
+    out.push_str(&names.join(",\n"));
+    out.push_str("\n);\n\n");
+}
+
+/// Generates the location-less `Hex` enum along with the flattened list of all locations
+fn gen_hex_list(out: &mut String, hexes: &[Hex]) {
+    out.push_str(
+        "/// Overall hex map tile without an inner location, see [Map::hex]
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hex {\n",
+    );
+    for hex in hexes {
+        writeln!(out, "    {},", hex.name).unwrap();
+    }
+    out.push_str("}\n\nimpl Hex {\n");
+
+    out.push_str("    /// Every hex of the map, in api order\n");
+    writeln!(out, "    pub const ALL: [Hex; {}] = [", hexes.len()).unwrap();
+    for hex in hexes {
+        writeln!(out, "        Hex::{},", hex.name).unwrap();
+    }
+    out.push_str("    ];\n\n");
+
+    out.push_str(
+        "    /// Gets hex from its api-centric name, e.g. `Stonecradle`
+    pub fn from_name(name: &str) -> Result<Self, LocationError> {
+        match name {\n",
+    );
+    for hex in hexes {
+        writeln!(out, "            \"{0}\" => Ok(Hex::{0}),", hex.name).unwrap();
+    }
+    out.push_str(
+        "            _ => Err(LocationError::UnknownHex(name.to_string())),\n        }\n    }\n",
+    );
+
+    for (func, doc) in [
+        ("name_api", "api-centric"),
+        ("name_friendly", "human-readable"),
+    ] {
+        writeln!(
+            out,
+            "\n    /// Provides the {} name for this hex\n    pub fn {}(&self) -> &'static str {{\n        match self {{",
+            doc, func
+        )
+        .unwrap();
+        for hex in hexes {
+            writeln!(out, "            Hex::{0} => \"{0}\",", hex.name).unwrap();
+        }
+        out.push_str("        }\n    }\n");
+    }
+
+    out.push_str(
+        "\n    /// Provides the full WarAPI map name for this hex as used by its endpoints, e.g. `StonecradleHex`
+    pub fn name_pos(&self) -> &'static str {
+        match self {\n",
+    );
+    for hex in hexes {
+        writeln!(out, "            Hex::{} => \"{}\",", hex.name, hex.pos).unwrap();
+    }
+    out.push_str("        }\n    }\n");
+
+    out.push_str("\n    /// Axial `q` and `r` grid position of this hex on the world map, see [grid]\n    pub fn axial(&self) -> (i32, i32) {\n        match self {\n");
+    for hex in hexes {
+        writeln!(
+            out,
+            "            Hex::{} => ({}, {}),",
+            hex.name, hex.axial.0, hex.axial.1
+        )
+        .unwrap();
+    }
+    out.push_str("        }\n    }\n");
+
+    out.push_str(
+        "\n    /// Range of this hex's locations within [Map::ALL]
+    fn span(&self) -> std::ops::Range<usize> {
+        match self {\n",
+    );
+    let mut start = 0;
+    for hex in hexes {
+        let end = start + hex.locations.len();
+        writeln!(out, "            Hex::{} => {}..{},", hex.name, start, end).unwrap();
+        start = end;
+    }
+    out.push_str("        }\n    }\n}\n\nimpl Map {\n");
+
+    out.push_str("    /// Every location of every hex, in hex and then declaration order\n");
+    writeln!(out, "    pub const ALL: [Map; {}] = [", start).unwrap();
+    for hex in hexes {
+        for loc in &hex.locations {
+            writeln!(out, "        Map::{0}({0}::{1}),", hex.name, loc.variant).unwrap();
+        }
+    }
+    out.push_str("    ];\n\n");
+
+    out.push_str(
+        "    /// Gets the hex this location is within
+    pub fn hex(&self) -> Hex {
+        match self {\n",
+    );
+    for hex in hexes {
+        writeln!(out, "            Map::{0}(_) => Hex::{0},", hex.name).unwrap();
+    }
+    out.push_str("        }\n    }\n}\n\n");
+}
+
+/// Generates the first war of each map version and which versions each hex and location is in
+fn gen_versions(out: &mut String, hexes: &[Hex], wars: &[i64]) {
+    let all = u64::MAX >> (64 - wars.len());
+
+    out.push_str("/// First war number which each version of the map applies to, oldest first, see [version]\n");
+    writeln!(
+        out,
+        "pub const MAP_VERSIONS: [i64; {}] = {:?};\n",
+        wars.len(),
+        wars
+    )
+    .unwrap();
+
+    let hex_masks: Vec<_> = hexes
+        .iter()
+        .filter(|hex| hex.versions != all)
+        .map(|hex| (format!("Hex::{}", hex.name), hex.versions))
+        .collect();
+    out.push_str("impl Hex {\n    /// Bitmask of which [MAP_VERSIONS] this hex is in\n");
+    gen_mask_fn(out, &hex_masks, all);
+    out.push_str("}\n\n");
+
+    let map_masks: Vec<_> = hexes
+        .iter()
+        .flat_map(|hex| {
+            hex.locations
+                .iter()
+                .filter(|loc| loc.versions != all)
+                .map(move |loc| {
+                    (
+                        format!("Map::{0}({0}::{1})", hex.name, loc.variant),
+                        loc.versions,
+                    )
+                })
+        })
+        .collect();
+    out.push_str("impl Map {\n    /// Bitmask of which [MAP_VERSIONS] this location is in\n");
+    gen_mask_fn(out, &map_masks, all);
+    out.push_str("}\n\n");
+}
+
+/// Generates a `version_mask` method, matching only the patterns which aren't in every version
+fn gen_mask_fn(out: &mut String, masks: &[(String, u64)], all: u64) {
+    out.push_str("    fn version_mask(&self) -> u64 {\n");
+    if masks.is_empty() {
+        writeln!(out, "        {:#b}", all).unwrap();
+    } else {
+        out.push_str("        match self {\n");
+        for (pattern, mask) in masks {
+            writeln!(out, "            {} => {:#b},", pattern, mask).unwrap();
+        }
+        writeln!(out, "            _ => {:#b},\n        }}", all).unwrap();
+    }
+    out.push_str("    }\n");
+}
+
+/// Generates a single hex enum along with its `Location` implementation
+fn gen_hex(out: &mut String, hex: &Hex) {
+    let name = &hex.name;
+
+    writeln!(out, "/// Specific map details for the `{}` tile", hex.pos).unwrap();
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    writeln!(out, "pub enum {} {{", name).unwrap();
+    for loc in &hex.locations {
+        writeln!(
+            out,
+            "    /// {} is a {} location\n    {},",
+            loc.name_friendly,
+            if loc.is_major { "major" } else { "minor" },
+            loc.variant
+        )
+        .unwrap();
+    }
+    out.push_str("}\n\n");
+
+    writeln!(out, "impl {} {{", name).unwrap();
+    out.push_str("    /// Every location within this hex, in declaration order\n");
+    writeln!(
+        out,
+        "    pub const ALL: [{}; {}] = [",
+        name,
+        hex.locations.len()
+    )
+    .unwrap();
+    for loc in &hex.locations {
+        writeln!(out, "        {}::{},", name, loc.variant).unwrap();
+    }
+    out.push_str("    ];\n}\n\n");
+
+    writeln!(out, "impl Location for {} {{", name).unwrap();
+    out.push_str(
+        "    fn from_name(name: &str) -> Result<Self, LocationError> {\n        match name {\n",
+    );
+    for loc in &hex.locations {
+        writeln!(
+            out,
+            "            \"{}\" => Ok({}::{}),",
+            loc.name_api, name, loc.variant
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "            _ => Err(LocationError::UnknownLocation(\n                \"{}\".to_string(),\n                name.to_string(),\n            )),\n        }}\n    }}",
+        name
+    )
+    .unwrap();
+
+    out.push_str("\n    fn is_major(&self) -> bool {\n        match self {\n");
+    for loc in &hex.locations {
+        writeln!(
+            out,
+            "            {}::{} => {},",
+            name, loc.variant, loc.is_major
+        )
+        .unwrap();
+    }
+    out.push_str("        }\n    }\n");
+
+    out.push_str("\n    fn coords(&self) -> (f64, f64) {\n        match self {\n");
+    for loc in &hex.locations {
+        writeln!(
+            out,
+            "            {}::{} => ({:?}, {:?}),",
+            name, loc.variant, loc.coords.0, loc.coords.1
+        )
+        .unwrap();
+    }
+    out.push_str("        }\n    }\n");
+
+    for (func, friendly) in [("name_api", false), ("name_friendly", true)].iter() {
+        writeln!(
+            out,
+            "\n    fn {}(&self) -> (&str, &str) {{\n        (\n            \"{}\",\n            match self {{",
+            func, name
+        )
+        .unwrap();
+        for loc in &hex.locations {
+            let value = if *friendly {
+                &loc.name_friendly
+            } else {
+                &loc.name_api
+            };
+            writeln!(
+                out,
+                "                {}::{} => \"{}\",",
+                name, loc.variant, value
+            )
+            .unwrap();
+        }
+        out.push_str("            },\n        )\n    }\n");
+    }
+    out.push_str("}\n\n");
+}
@@ -0,0 +1,20 @@
+CREATE TABLE ownership (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    war_num INTEGER NOT NULL,
+    map_location VARCHAR(64) NOT NULL,
+    team VARCHAR(16),
+    flags INTEGER NOT NULL,
+    at_time DATETIME NOT NULL,
+    submitted DATETIME NOT NULL,
+    FOREIGN KEY (war_num) REFERENCES war(num)
+);
+
+CREATE INDEX ownership_location ON ownership (war_num, map_location, at_time);
+
+-- the id is nicknamed the "ownership id"
+-- war_num is the war number this took place in, foreign key to war
+-- map_location is the town or relic base as defined by api
+-- team is the api name of the faction holding it, or null if nobody does
+-- flags are the api bitflags of the town or relic base, such as if it is a victory base
+-- at_time is the time this ownership was first seen, only changes are stored
+-- submitted was when it was submitted to database
\ No newline at end of file
@@ -0,0 +1,8 @@
+ALTER TABLE battle ADD COLUMN colonials INTEGER;
+ALTER TABLE battle ADD COLUMN wardens INTEGER;
+ALTER TABLE population ADD COLUMN colonials INTEGER;
+ALTER TABLE population ADD COLUMN wardens INTEGER;
+
+-- colonials and wardens are how many of each faction were fighting, null if unknown
+-- for battles these are the most seen fighting at once, going up as population reports come in
+-- for population reports these split the counted population between factions, so add up to counted
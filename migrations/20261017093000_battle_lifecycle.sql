@@ -0,0 +1,11 @@
+ALTER TABLE battle ADD COLUMN started_at DATETIME;
+ALTER TABLE battle ADD COLUMN ended_at DATETIME;
+ALTER TABLE battle ADD COLUMN status VARCHAR(16) NOT NULL DEFAULT 'ongoing';
+ALTER TABLE battle ADD COLUMN victor VARCHAR(16);
+
+UPDATE battle SET started_at = submitted;
+
+-- started_at is when fighting began, battles from before this was tracked started when submitted
+-- ended_at is when fighting stopped, null whilst ongoing
+-- status is either "ongoing" or "concluded"
+-- victor is the api name of the winning faction once concluded, null if ongoing or unknown
@@ -0,0 +1,16 @@
+CREATE TABLE battle_location (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    battle_id INTEGER NOT NULL,
+    map_location VARCHAR(64) NOT NULL,
+    at_time DATETIME NOT NULL,
+    submitted DATETIME NOT NULL,
+    FOREIGN KEY (battle_id) REFERENCES battle(id)
+);
+
+CREATE INDEX battle_location_battle ON battle_location (battle_id, at_time);
+
+-- the id is nicknamed the "battle location id"
+-- battle id is foreign id of a battle, whose own map_location is where fighting started
+-- map_location is a game location as defined by api which fighting moved onto
+-- at_time is when fighting reached this location, a battle may move back onto an earlier location
+-- submitted was when it was submitted to database
@@ -1,87 +1,45 @@
-"""Formats map hexes from warapi into a Rust enumeration"""
+"""Dumps static map hexes from warapi into `data/maps/{war}.json`, which `build.rs` generates `map.rs` from"""
+
+import json
+from pathlib import Path
 
 import requests
 
+WAR_URL = "https://war-service-live.foxholeservices.com/api/worldconquest/war"
 HEX_URL = "https://war-service-live.foxholeservices.com/api/worldconquest/maps"
 LOCATION_PREFIX_URL = (
     "https://war-service-live.foxholeservices.com/api/worldconquest/maps/"
 )
 LOCATION_SUFFIX_URL = "/static"
-DIV = "--------------------------"
+DUMP_DIR = Path(__file__).parent.parent / "data" / "maps"
 
-print("Starting map formatting..")
-buffer = ""  # primary buffer, instead of printing
+print("Starting map dump..")
 
+war_num = requests.get(WAR_URL).json()["warNumber"]
 hexes = requests.get(HEX_URL).json()
-hex_enum = (
-    "/// Hex name of a single map tile, topmost of all map details\npub enum MapHex {\n"
-)
+dump = {"maps": hexes, "static": {}}
 
 for pos in hexes:
-    name = pos[:-3]
-    hex_enum += f"    {name}({name}),\n"
-
-    print(f"Getting details for {name} hex..")
-
-    detailed = requests.get(f"{LOCATION_PREFIX_URL}{pos}{LOCATION_SUFFIX_URL}").json()[
-        "mapTextItems"
-    ]
-
-    buffer += (
-        f"/// Specific map details for the `{pos}` tile\npub enum {name} {{"
-    ) + "\n"
-    hex_impl_major = f"impl MapLocation for {name} {{\n    fn is_major(&self) -> bool {{\n        match self {{\n"
-    hex_impl_location = (
-        f"    fn location(&self) -> (f64, f64) {{\n        match self {{\n"
-    )
-    hex_impl_name_api = (
-        f'    fn name_api(&self) -> &str {{\n        ("{name}", match self {{\n'
-    )
-    hex_impl_name_friendly = f'    fn name_friendly(&self) -> &str {{\n        ("{name}", match self {{\n'  # TODO get space splitted name
-
-    for detail in detailed:
-        detailrawname = detail["text"]
-        detailname = detailrawname.replace(" ", "").replace("'", "")
-        morm = detail["mapMarkerType"].lower()
-        is_morm = str(morm == "major").lower()
-
-        print(f"  Getting details for {detailname} position..")
-
-        _location_x = str(detail["x"])
-        _location_y = str(detail["y"])
-        location = f"({_location_x}, {_location_y})"
-
-        buffer += (
-            f"    /// {detailrawname} is a {morm} location\n    {detailname},"
-        ) + "\n"
-        hex_impl_major += f"            {name}::{detailname} => {is_morm},\n"
-        hex_impl_location += f"            {name}::{detailname} => {location},\n"
-        hex_impl_name_api += f'            {name}::{detailname} => "{detailname}",\n'
-        hex_impl_name_friendly += (
-            f'            {name}::{detailname} => "{detailrawname}",\n'
-        )
-
-    buffer += ("}\n") + "\n"
-    buffer += (hex_impl_major + "        }\n    }\n") + "\n"
-    buffer += (hex_impl_location + "        }\n    }\n") + "\n"
-    buffer += (hex_impl_name_api + "        })\n    }\n") + "\n"
-    buffer += (hex_impl_name_friendly + "        })\n    }\n}\n") + "\n"
-
-hex_enum += "}"
-
-buffer += (
-    f"{DIV}\n{DIV}\n{DIV}\nADD ALL BELOW TO THE TOP OF FILE\n{DIV}\n{DIV}\n{DIV}\n"
-) + "\n"
-buffer += (hex_enum) + "\n"
-
-# 18th sideroad or something is broken with rust, so format the NON-SPACED one
-sideroadfix = "EighteenthS"
-buffer.replace(" 18thS", sideroadfix)
-buffer.replace(
-    "::18thS",sideroadfix
-)  
-
-print("Saving buffer..")
-
-with open("mapdump.txt", "w+") as file:
-    file.write(buffer)
+    print(f"Getting details for {pos} hex..")
+    static = requests.get(f"{LOCATION_PREFIX_URL}{pos}{LOCATION_SUFFIX_URL}").json()
+    # only names are used by `build.rs`, everything else changes every request
+    dump["static"][pos] = {
+        "mapTextItems": [
+            {key: item[key] for key in ("text", "x", "y", "mapMarkerType")}
+            for item in static["mapTextItems"]
+        ]
+    }
+
+latest = max(DUMP_DIR.glob("*.json"), key=lambda path: int(path.stem), default=None)
+
+if latest is not None:
+    with open(latest, encoding="utf-8") as file:
+        if json.load(file) == dump:
+            print(f"Map unchanged since war {latest.stem}, not saving")
+            exit()
+
+print(f"Saving dump for war {war_num}..")
+
+with open(DUMP_DIR / f"{war_num}.json", "w+", encoding="utf-8") as file:
+    json.dump(dump, file, indent=2, ensure_ascii=False)
+    file.write("\n")
@@ -1,6 +1,8 @@
 //! Contains [Result], [Error] and implementations
 
+use crate::map::LocationError;
 use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use chrono::NaiveDateTime;
 use serde::Serialize;
 use std::{fmt, io};
 
@@ -15,13 +17,35 @@ pub enum Error {
     /// Database error from sqlx
     Database(sqlx::Error),
     /// Map location couldn't be found
-    LocationNotFound,
+    LocationNotFound(LocationError),
     /// War of number couldn't be found
     WarNotFound(i64),
+    /// War of number has already been added
+    WarExists(i64),
+    /// War was given an end time without a winner or the other way around
+    WarEndIncomplete,
     /// Battle of id couldn't be found
     BattleNotFound(i64),
+    /// Battle of id has already been concluded
+    BattleConcluded(i64),
+    /// Battle or war would end before it started
+    EndedBeforeStart,
+    /// Population report of battle id at a time couldn't be found
+    PopulationNotFound(i64, NaiveDateTime),
+    /// Battle of id already has a population report at a time
+    PopulationExists(i64, NaiveDateTime),
+    /// Faction counts of a population report add up to more than was counted
+    FactionsOverCounted,
+    /// Population report counted less than nobody
+    NegativeCount,
     /// There was an error rendering templates using tera
     TemplateRender(tera::Error),
+    /// Request to the WarAPI failed or gave something unexpected
+    WarApi(reqwest::Error),
+    /// WarAPI gave data which couldn't be decoded
+    WarApiData(serde_json::Error),
+    /// Continuing after a cursor was asked for on a list which can only be paged through by number
+    CursorUnsupported,
     /// Data provided for an input was too short
     DataTooShort,
     /// Data provided for an input was too long
@@ -34,22 +58,67 @@ impl From<sqlx::Error> for Error {
     }
 }
 
+impl From<LocationError> for Error {
+    fn from(err: LocationError) -> Self {
+        Self::LocationNotFound(err)
+    }
+}
+
 impl From<tera::Error> for Error {
     fn from(err: tera::Error) -> Self {
         Self::TemplateRender(err)
     }
 }
 
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Self::WarApi(err)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // TODO: add mixed logging here too
         match self {
             Error::StaticOpen(_) => write!(f, "Could not retrieve html file from static files"),
             Error::Database(_) => write!(f, "Database error"),
-            Error::LocationNotFound => write!(f, "Map location provided could not be found"),
+            Error::LocationNotFound(err) => {
+                write!(f, "Map location provided could not be found, {}", err)
+            }
             Error::WarNotFound(num) => write!(f, "War number {} could not be found", num),
+            Error::WarExists(num) => write!(f, "War number {} already exists", num),
+            Error::WarEndIncomplete => {
+                write!(f, "War end time and winner must be given together")
+            }
             Error::BattleNotFound(id) => write!(f, "Battle id {} could not be found", id),
+            Error::BattleConcluded(id) => write!(f, "Battle id {} has already been concluded", id),
+            Error::EndedBeforeStart => write!(f, "Cannot end before it started"),
+            Error::PopulationNotFound(id, at_time) => write!(
+                f,
+                "Population report of battle id {} at {} could not be found",
+                id, at_time
+            ),
+            Error::PopulationExists(id, at_time) => write!(
+                f,
+                "Battle id {} already has a population report at {}",
+                id, at_time
+            ),
             Error::TemplateRender(_) => write!(f, "Could not properly render html template"),
+            Error::WarApi(err) => write!(f, "Could not get data from the WarAPI, {}", err),
+            Error::WarApiData(err) => write!(f, "WarAPI gave unexpected data, {}", err),
+            Error::FactionsOverCounted => {
+                write!(
+                    f,
+                    "Faction counts add up to more than the population counted"
+                )
+            }
+            Error::NegativeCount => write!(f, "Counts cannot be negative"),
+            Error::CursorUnsupported => {
+                write!(
+                    f,
+                    "This list can only be paged through by number, not a cursor"
+                )
+            }
             Error::DataTooShort => write!(f, "Inputted data was too short"),
             Error::DataTooLong => write!(f, "Inputted data was too long"),
         }
@@ -63,10 +132,21 @@ impl ResponseError for Error {
             Error::StaticOpen(_) | Error::Database(_) | Error::TemplateRender(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
-            Error::LocationNotFound | Error::WarNotFound(_) | Error::BattleNotFound(_) => {
-                StatusCode::NOT_FOUND
+            Error::LocationNotFound(_)
+            | Error::WarNotFound(_)
+            | Error::BattleNotFound(_)
+            | Error::PopulationNotFound(_, _) => StatusCode::NOT_FOUND,
+            Error::DataTooShort
+            | Error::DataTooLong
+            | Error::EndedBeforeStart
+            | Error::WarEndIncomplete
+            | Error::CursorUnsupported
+            | Error::FactionsOverCounted
+            | Error::NegativeCount => StatusCode::BAD_REQUEST,
+            Error::BattleConcluded(_) | Error::WarExists(_) | Error::PopulationExists(_, _) => {
+                StatusCode::CONFLICT
             }
-            Error::DataTooShort | Error::DataTooLong => StatusCode::BAD_REQUEST,
+            Error::WarApi(_) | Error::WarApiData(_) => StatusCode::BAD_GATEWAY,
         }
     }
 
@@ -0,0 +1,171 @@
+//! Automatic detection of battles from casualty spikes in the `/worldconquest/warReport/{hex}` endpoint,
+//! with their location inferred from `/worldconquest/maps/{hex}/dynamic/public`
+
+use super::{ActiveBattle, Counters, Ingester};
+use crate::map::{Hex, Location, Map};
+use crate::models::{Battle, Ownership};
+use crate::warapi::{DynamicMap, MapItem};
+use crate::Result;
+use chrono::NaiveDateTime;
+use log::{info, trace};
+
+/// Lowest casualties per minute which keep a battle going
+const ACTIVE_RATE: f64 = 2.0;
+
+/// How long a battle may go without fighting before it's no longer followed, in minutes
+const BATTLE_TIMEOUT: i64 = 30;
+
+impl Ingester {
+    /// Polls the casualties of every hex, creating battles for sudden spikes and following those still
+    /// being fought
+    pub(super) async fn poll_battles(&self, war_num: i64) -> Result<()> {
+        let now = self.now();
+        for hex in Hex::all_in_war(war_num) {
+            let report = self.api.war_report(hex).await?;
+            let reading = self
+                .state
+                .lock()
+                .unwrap()
+                .detector
+                .record(hex, report.casualties(), now);
+            let reading = match reading {
+                Some(reading) => reading,
+                None => continue,
+            };
+            trace!("{} has {:.1} casualties per minute", hex, reading.rate);
+
+            let (is_active, died_down) = {
+                let mut state = self.state.lock().unwrap();
+                match state.battles.get_mut(&hex) {
+                    Some(active) if reading.rate >= ACTIVE_RATE => {
+                        active.last_active = now;
+                        (true, None)
+                    }
+                    Some(active) if (now - active.last_active).num_minutes() > BATTLE_TIMEOUT => {
+                        info!("Battle of id {} in {} has died down", active.id, hex);
+                        (false, state.battles.remove(&hex))
+                    }
+                    Some(_) => (true, None),
+                    None => (false, None),
+                }
+            };
+            if let Some(died_down) = died_down {
+                self.conclude_battle(war_num, died_down).await?;
+            }
+            self.report_population(hex, &report, now).await?;
+            if !reading.spike {
+                continue;
+            }
+
+            let dynamic = self.api.dynamic_map(hex).await?;
+            let map = match self.infer_location(hex, war_num, &dynamic) {
+                Some(map) => map,
+                None => continue,
+            };
+            if is_active {
+                self.follow_front(hex, map, now).await?;
+                continue;
+            }
+
+            info!(
+                "Detected new battle at {} with {:.1} casualties per minute",
+                map, reading.rate
+            );
+            let battle = Battle::new(&self.pool, war_num, map, now, None, None).await?;
+            self.state.lock().unwrap().battles.insert(
+                hex,
+                ActiveBattle {
+                    id: battle.id,
+                    map,
+                    last_active: now,
+                    reported: Counters::new(&report, now),
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Follows a battle still being fought within a hex onto the location of a fresh spike, if fighting
+    /// has moved somewhere else
+    async fn follow_front(&self, hex: Hex, map: Map, now: NaiveDateTime) -> Result<()> {
+        let moved = {
+            let mut state = self.state.lock().unwrap();
+            match state.battles.get_mut(&hex) {
+                Some(active) if active.map != map => {
+                    active.map = map;
+                    Some(active.id)
+                }
+                _ => None,
+            }
+        };
+        if let Some(battle_id) = moved {
+            info!("Battle of id {} has moved onto {}", battle_id, map);
+            Battle::get_ensure(&self.pool, battle_id)
+                .await?
+                .add_location(&self.pool, map, now)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Concludes a battle which has died down at the last time fighting was seen, counting whoever holds
+    /// the location it was last fought over afterwards as the victor
+    async fn conclude_battle(&self, war_num: i64, battle: ActiveBattle) -> Result<()> {
+        let victor = Ownership::get_latest(&self.pool, war_num, battle.map)
+            .await?
+            .and_then(|ownership| ownership.team);
+        Battle::conclude(&self.pool, battle.id, battle.last_active, victor).await
+    }
+
+    /// Infers where fighting within a hex is from the closest opposing structures, falling back to the
+    /// middle of the hex if only one faction is there
+    fn infer_location(&self, hex: Hex, war_num: i64, dynamic: &DynamicMap) -> Option<Map> {
+        let point = contact_point(&dynamic.map_items).unwrap_or((0.5, 0.5));
+        self.index
+            .nearest_by(hex.to_world(point), |map| {
+                map.hex() == hex && map.is_major() && map.existed_in(war_num)
+            })
+            .map(|(map, _)| map)
+    }
+}
+
+/// Finds the midpoint between the closest two structures held by opposing factions, which is where
+/// fighting is most likely to be happening
+fn contact_point(items: &[MapItem]) -> Option<(f64, f64)> {
+    let held = |team: &'static str| items.iter().filter(move |item| item.team_id == team);
+    held("COLONIALS")
+        .flat_map(|colonial| held("WARDENS").map(move |warden| (colonial, warden)))
+        .map(|(a, b)| ((a.x - b.x).hypot(a.y - b.y), a, b))
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+        .map(|(_, a, b)| ((a.x + b.x) / 2.0, (a.y + b.y) / 2.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(team_id: &str, x: f64, y: f64) -> MapItem {
+        MapItem {
+            team_id: team_id.to_string(),
+            icon_type: 56,
+            x,
+            y,
+            flags: 0,
+        }
+    }
+
+    #[test]
+    fn contact() {
+        let items = vec![
+            item("COLONIALS", 0.1, 0.1),
+            item("COLONIALS", 0.5, 0.5),
+            item("WARDENS", 0.6, 0.5),
+            item("WARDENS", 0.9, 0.9),
+            item("NONE", 0.55, 0.5),
+        ];
+        let (x, y) = contact_point(&items).unwrap();
+        assert!((x - 0.55).abs() < 1e-9 && (y - 0.5).abs() < 1e-9);
+        assert_eq!(contact_point(&items[..2]), None);
+    }
+}
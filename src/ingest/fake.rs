@@ -0,0 +1,468 @@
+//! Fake WarAPI server for developing and testing ingestion offline, see [FakeWarApi]
+//!
+//! Scenarios are json files with the unix time in milliseconds the fake clock starts at, how many
+//! seconds each step moves it along and then a list of steps. Each step may give the war state, war
+//! reports by hex and dynamic maps by hex as the real WarAPI would, with anything not given carrying on
+//! from earlier steps:
+//!
+//! ```json
+//! {
+//!     "start": 1629892800000,
+//!     "stepSecs": 300,
+//!     "steps": [
+//!         {
+//!             "war": { "warNumber": 83, "winner": "NONE", "conquestStartTime": 1629892800000 },
+//!             "warReports": { "DeadLandsHex": { "totalEnlistments": 100, "colonialCasualties": 500, "wardenCasualties": 500, "dayOfWar": 1 } },
+//!             "dynamic": { "DeadLandsHex": { "mapItems": [] } }
+//!         }
+//!     ]
+//! }
+//! ```
+
+use crate::warapi::from_millis;
+use actix_web::dev::Server;
+use actix_web::http::header;
+use actix_web::{get, post, put, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
+use chrono::{Duration, NaiveDateTime};
+use log::{info, trace};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::{fs, io, path::Path};
+
+/// Directory of the map dumps which static maps are served from, see the build script
+const DUMP_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/maps");
+
+/// Scripted progression of the WarAPI, loaded from json
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Scenario {
+    /// Unix time in milliseconds of the first step
+    start: i64,
+    /// Seconds between each step
+    step_secs: i64,
+    steps: Vec<Step>,
+}
+
+/// Everything the WarAPI changed to at one step of a [Scenario]
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct Step {
+    war: Option<Value>,
+    #[serde(default)]
+    war_reports: HashMap<String, Value>,
+    #[serde(default)]
+    dynamic: HashMap<String, Value>,
+}
+
+/// Map dump as written by `scripts/maphexes.py`
+#[derive(Deserialize)]
+struct Dump {
+    maps: Vec<String>,
+    #[serde(rename = "static")]
+    statics: HashMap<String, Value>,
+}
+
+/// Replays a recorded [Scenario] as the WarAPI, moving through its steps when told to rather than in
+/// real time so ingestion can be tested end to end without a network
+#[derive(Clone)]
+pub struct FakeWarApi {
+    scenario: Arc<Scenario>,
+    start: NaiveDateTime,
+    dump: Arc<Dump>,
+    step: Arc<AtomicUsize>,
+}
+
+impl FakeWarApi {
+    /// Loads a scenario from json, serving static maps from the newest map dump
+    pub fn new(scenario: &str) -> io::Result<Self> {
+        let scenario: Scenario = serde_json::from_str(scenario)?;
+        if scenario.steps.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Scenario has no steps",
+            ));
+        }
+        let start = from_millis(scenario.start)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+        Ok(Self {
+            start,
+            scenario: Arc::new(scenario),
+            dump: Arc::new(newest_dump()?),
+            step: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Loads a scenario from a json file, see [FakeWarApi::new]
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(&fs::read_to_string(path)?)
+    }
+
+    /// Current step of the scenario, starting at `0`
+    pub fn step(&self) -> usize {
+        self.step.load(Ordering::SeqCst)
+    }
+
+    /// Moves to a step, staying on the last one if past the end, and gives the step moved to
+    pub fn set_step(&self, step: usize) -> usize {
+        let step = step.min(self.scenario.steps.len() - 1);
+        self.step.store(step, Ordering::SeqCst);
+        trace!("Fake WarAPI moved to step {}", step);
+        step
+    }
+
+    /// Moves onto the next step, giving the step moved to
+    pub fn advance(&self) -> usize {
+        self.set_step(self.step() + 1)
+    }
+
+    /// Indicates if the scenario is on its last step
+    pub fn is_finished(&self) -> bool {
+        self.step() + 1 == self.scenario.steps.len()
+    }
+
+    /// Fake time of the current step, which should be used as the clock of an ingester polling this
+    pub fn now(&self) -> NaiveDateTime {
+        self.start + Duration::seconds(self.scenario.step_secs * self.step() as i64)
+    }
+
+    /// Adds the fake WarAPI routes under `/api` along with `/fake` routes for moving through steps
+    pub fn configure(&self, cfg: &mut web::ServiceConfig) {
+        cfg.app_data(web::Data::new(self.clone()));
+        cfg.service(get_war);
+        cfg.service(get_maps);
+        cfg.service(get_static);
+        cfg.service(get_dynamic);
+        cfg.service(get_war_report);
+        cfg.service(post_advance);
+        cfg.service(put_step);
+    }
+
+    /// Binds a server for this fake to an address, which has to be awaited or spawned to run
+    pub fn serve(self, addr: (&str, u16)) -> io::Result<Server> {
+        info!("Serving fake WarAPI at http://{}:{}/api", addr.0, addr.1);
+        Ok(
+            HttpServer::new(move || App::new().configure(|cfg| self.configure(cfg)))
+                .bind(addr)?
+                .run(),
+        )
+    }
+
+    /// Latest value given for something up to the current step, along with the step it was given at
+    fn latest<'a>(
+        &'a self,
+        get: impl Fn(&'a Step) -> Option<&'a Value>,
+    ) -> Option<(usize, &'a Value)> {
+        self.scenario.steps[..=self.step()]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(num, step)| get(step).map(|value| (num, value)))
+    }
+
+    /// Indicates if a hex is on the map, as the WarAPI 404s for any other
+    fn has_hex(&self, name_pos: &str) -> bool {
+        self.dump.maps.iter().any(|map| map == name_pos)
+    }
+}
+
+/// Reads the map dump of the highest war number
+fn newest_dump() -> io::Result<Dump> {
+    let mut newest = None;
+    for entry in fs::read_dir(DUMP_DIR)? {
+        let path = entry?.path();
+        let war_num = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<i64>().ok());
+        newest = match (war_num, newest) {
+            (Some(war_num), Some((num, _))) if war_num > num => Some((war_num, path)),
+            (Some(war_num), None) => Some((war_num, path)),
+            (_, newest) => newest,
+        };
+    }
+    match newest {
+        Some((_, path)) => Ok(serde_json::from_str(&fs::read_to_string(path)?)?),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No map dumps to serve static maps from",
+        )),
+    }
+}
+
+/// Responds with the latest value given by the scenario, tagged by the step it was given at so
+/// clients sending that `ETag` back get a `304 Not Modified` until it changes
+fn replay(req: &HttpRequest, latest: Option<(usize, &Value)>, default: Value) -> HttpResponse {
+    let etag = match latest {
+        Some((num, _)) => format!("\"step-{}\"", num),
+        None => "\"default\"".to_string(),
+    };
+    let if_none_match = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok());
+    if if_none_match == Some(etag.as_str()) {
+        return HttpResponse::NotModified().finish();
+    }
+
+    let mut resp = HttpResponse::Ok();
+    resp.insert_header((header::ETAG, etag));
+    match latest {
+        Some((_, value)) => resp.json(value),
+        None => resp.json(default),
+    }
+}
+
+/// Gets state of the war
+#[get("/api/worldconquest/war")]
+async fn get_war(req: HttpRequest, fake: web::Data<FakeWarApi>) -> impl Responder {
+    match fake.latest(|step| step.war.as_ref()) {
+        Some(war) => replay(&req, Some(war), Value::Null),
+        None => HttpResponse::NotFound().finish(),
+    }
+}
+
+/// Gets names of every hex
+#[get("/api/worldconquest/maps")]
+async fn get_maps(fake: web::Data<FakeWarApi>) -> impl Responder {
+    HttpResponse::Ok().json(&fake.dump.maps)
+}
+
+/// Gets static map of a hex from the map dump
+#[get("/api/worldconquest/maps/{hex}/static")]
+async fn get_static(fake: web::Data<FakeWarApi>, hex: web::Path<String>) -> impl Responder {
+    match fake.dump.statics.get(hex.as_str()) {
+        Some(map) => HttpResponse::Ok().json(map),
+        None => HttpResponse::NotFound().finish(),
+    }
+}
+
+/// Gets dynamic map of a hex, which has no structures until the scenario gives some
+#[get("/api/worldconquest/maps/{hex}/dynamic/public")]
+async fn get_dynamic(
+    req: HttpRequest,
+    fake: web::Data<FakeWarApi>,
+    hex: web::Path<String>,
+) -> impl Responder {
+    if !fake.has_hex(&hex) {
+        return HttpResponse::NotFound().finish();
+    }
+    replay(
+        &req,
+        fake.latest(|step| step.dynamic.get(hex.as_str())),
+        json!({ "mapItems": [] }),
+    )
+}
+
+/// Gets war report of a hex, which has no casualties until the scenario gives some
+#[get("/api/worldconquest/warReport/{hex}")]
+async fn get_war_report(
+    req: HttpRequest,
+    fake: web::Data<FakeWarApi>,
+    hex: web::Path<String>,
+) -> impl Responder {
+    if !fake.has_hex(&hex) {
+        return HttpResponse::NotFound().finish();
+    }
+    replay(
+        &req,
+        fake.latest(|step| step.war_reports.get(hex.as_str())),
+        json!({
+            "totalEnlistments": 0,
+            "colonialCasualties": 0,
+            "wardenCasualties": 0,
+            "dayOfWar": 0,
+        }),
+    )
+}
+
+/// Moves onto the next step of the scenario
+#[post("/fake/advance")]
+async fn post_advance(fake: web::Data<FakeWarApi>) -> impl Responder {
+    HttpResponse::Ok().json(json!({ "step": fake.advance() }))
+}
+
+/// Moves to a step of the scenario
+#[put("/fake/step/{step}")]
+async fn put_step(fake: web::Data<FakeWarApi>, step: web::Path<usize>) -> impl Responder {
+    HttpResponse::Ok().json(json!({ "step": fake.set_step(step.into_inner()) }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ingest::Ingester;
+    use crate::map::{DeadLands, Faction, Map};
+    use crate::models::{test_pool, Battle, BattleStatus, FactionCounts, Ownership, War};
+    use crate::warapi::WarApi;
+
+    const SCENARIO: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/scenarios/deadlands.json");
+    const ROLLOVER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/scenarios/rollover.json");
+
+    #[test]
+    fn carries_forward() {
+        let fake = FakeWarApi::open(SCENARIO).unwrap();
+        let report_step = |fake: &FakeWarApi| {
+            fake.latest(|step| step.war_reports.get("DeadLandsHex"))
+                .map(|(num, _)| num)
+        };
+        assert_eq!(fake.advance(), 1);
+        assert_eq!(report_step(&fake), Some(1));
+        assert_eq!(
+            fake.latest(|step| step.war.as_ref()).map(|(num, _)| num),
+            Some(0)
+        );
+        assert_eq!(
+            fake.now() - from_millis(1629892800000).unwrap(),
+            Duration::minutes(5)
+        );
+
+        assert_eq!(fake.set_step(1000), 16);
+        assert!(fake.is_finished());
+        assert_eq!(report_step(&fake), Some(8));
+    }
+
+    /// Serves a fake on a free port, giving the base url of its WarAPI
+    fn spawn_server(fake: &FakeWarApi) -> String {
+        let fake = fake.clone();
+        let server = HttpServer::new(move || App::new().configure(|cfg| fake.configure(cfg)))
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+        let base_url = format!("http://{}/api", server.addrs()[0]);
+        actix_web::rt::spawn(server.run());
+        base_url
+    }
+
+    #[test]
+    fn not_modified() {
+        actix_web::rt::System::new().block_on(async {
+            let fake = FakeWarApi::open(SCENARIO).unwrap();
+            let base_url = spawn_server(&fake);
+            let url = format!("{}/worldconquest/war", base_url);
+            let client = reqwest::Client::new();
+
+            let resp = client.get(&url).send().await.unwrap();
+            let etag = resp.headers()["etag"].to_str().unwrap().to_string();
+            let resend = || client.get(&url).header("if-none-match", etag.as_str());
+            assert_eq!(resend().send().await.unwrap().status(), 304);
+            fake.set_step(16);
+            assert_eq!(resend().send().await.unwrap().status(), 200);
+
+            // cached body is used again after a 304
+            let api = WarApi::new(base_url);
+            assert_eq!(api.war().await.unwrap(), api.war().await.unwrap());
+        })
+    }
+
+    #[test]
+    fn ingests_scenario() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = test_pool().await;
+
+            let fake = FakeWarApi::open(SCENARIO).unwrap();
+            let base_url = spawn_server(&fake);
+
+            let ingester = Ingester::new(pool.clone(), WarApi::new(base_url)).clock({
+                let fake = fake.clone();
+                move || fake.now()
+            });
+            loop {
+                ingester.poll().await.unwrap();
+                if fake.is_finished() {
+                    break;
+                }
+                fake.advance();
+            }
+
+            let war = War::get_ensure(&pool, 83).await.unwrap();
+            assert_eq!(war.colonial_win, Some(false));
+
+            let battles = Battle::get_war(&pool, 83).await.unwrap();
+            assert_eq!(battles.len(), 1);
+            let battle = battles
+                .into_iter()
+                .next()
+                .unwrap()
+                .get_pop_reports(&pool)
+                .await
+                .unwrap();
+            assert_eq!(battle.map, Map::DeadLands(DeadLands::ThePits));
+            let counted: Vec<i64> = battle
+                .pop_reports
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|report| report.counted)
+                .collect();
+            assert_eq!(counted, vec![340, 42, 0]);
+            assert_eq!(battle.factions(), Some(FactionCounts::new(172, 168)));
+
+            // fighting stopped at step 8, with the colonials still holding the town
+            assert_eq!(battle.status, BattleStatus::Concluded);
+            assert_eq!(battle.ended_at, Some(fake.now() - Duration::minutes(40)));
+            assert_eq!(battle.victor, Some(Faction::Colonial));
+
+            let history =
+                Ownership::get_history(&pool, 83, Map::DeadLands(DeadLands::AbandonedWard))
+                    .await
+                    .unwrap();
+            let teams: Vec<_> = history.iter().map(|ownership| ownership.team).collect();
+            assert_eq!(teams, vec![Some(Faction::Warden), Some(Faction::Colonial)]);
+        })
+    }
+
+    #[test]
+    fn ingests_rollover() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = test_pool().await;
+            let fake = FakeWarApi::open(ROLLOVER).unwrap();
+            let base_url = spawn_server(&fake);
+
+            let ingester = Ingester::new(pool.clone(), WarApi::new(base_url)).clock({
+                let fake = fake.clone();
+                move || fake.now()
+            });
+            loop {
+                ingester.poll().await.unwrap();
+                if fake.is_finished() {
+                    break;
+                }
+                fake.advance();
+            }
+
+            let war = War::get_ensure(&pool, 83).await.unwrap();
+            assert_eq!(war.colonial_win, Some(false));
+            assert!(War::get_ensure(&pool, 84).await.unwrap().time_end.is_none());
+
+            // battle was still being fought when the war ended, so ends with it
+            let battles = Battle::get_war(&pool, 83).await.unwrap();
+            assert_eq!(battles.len(), 1);
+            assert_eq!(battles[0].status, BattleStatus::Concluded);
+            assert_eq!(battles[0].ended_at, war.time_end);
+            assert!(Battle::get_war(&pool, 84).await.unwrap().is_empty());
+        })
+    }
+
+    #[test]
+    fn leaves_missed_end_open() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = test_pool().await;
+            let fake = FakeWarApi::open(ROLLOVER).unwrap();
+            let base_url = spawn_server(&fake);
+
+            let ingester = Ingester::new(pool.clone(), WarApi::new(base_url)).clock({
+                let fake = fake.clone();
+                move || fake.now()
+            });
+            ingester.poll().await.unwrap();
+            fake.set_step(6); // skipping over the end of war 83
+            ingester.poll().await.unwrap();
+
+            let war = War::get_ensure(&pool, 83).await.unwrap();
+            assert_eq!((war.time_end, war.colonial_win), (None, None));
+            assert!(War::get_ensure(&pool, 84).await.is_ok());
+        })
+    }
+}
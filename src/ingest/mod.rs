@@ -0,0 +1,132 @@
+//! Background ingestion of the Foxhole [WarAPI](https://github.com/clapfoot/warapi) into the database,
+//! see [Ingester]
+
+mod battle;
+mod fake;
+mod ownership;
+mod population;
+mod spike;
+mod war;
+
+pub use fake::FakeWarApi;
+pub use spike::{Reading, SpikeDetector};
+
+use crate::map::{Faction, Hex, Map, SpatialIndex};
+use crate::models::FactionCounts;
+use crate::warapi::{WarApi, WarReport};
+use crate::Result;
+use chrono::{NaiveDateTime, Utc};
+use log::{info, warn};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Time between each poll of the WarAPI, which asks to not be hammered
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically polls the WarAPI and records what it finds, such as the current war and battles within it
+pub struct Ingester {
+    pool: SqlitePool,
+    api: WarApi,
+    interval: Duration,
+    index: SpatialIndex,
+    state: Mutex<IngestState>,
+    /// Gives the current time, which is faked when polling a [FakeWarApi]
+    clock: Box<dyn Fn() -> NaiveDateTime + Send + Sync>,
+}
+
+/// Everything remembered between polls, which is lost when restarting
+#[derive(Default)]
+struct IngestState {
+    detector: SpikeDetector,
+    /// Battles which are still being fought, at most one per hex
+    battles: HashMap<Hex, ActiveBattle>,
+    /// Last known team and flags of each town and relic base in the current war
+    owners: HashMap<Map, (Option<Faction>, i64)>,
+}
+
+/// Battle which is still being fought, detected by [Ingester::poll_battles]
+struct ActiveBattle {
+    id: i64,
+    /// Location fighting was last inferred to be at, which moves as the front does
+    map: Map,
+    /// Last time fighting was seen in the hex of this battle
+    last_active: NaiveDateTime,
+    /// Counters of the hex when this battle was last reported on, see [Ingester::report_population]
+    reported: Counters,
+}
+
+/// Casualty and enlistment counters of a hex at a point in time
+struct Counters {
+    at: NaiveDateTime,
+    casualties: FactionCounts,
+    enlistments: i64,
+}
+
+impl Counters {
+    /// Takes the counters of a war report made at a time
+    fn new(report: &WarReport, at: NaiveDateTime) -> Self {
+        Self {
+            at,
+            casualties: FactionCounts::new(report.colonial_casualties, report.warden_casualties),
+            enlistments: report.total_enlistments,
+        }
+    }
+}
+
+impl Ingester {
+    /// Creates a new ingester polling through a WarAPI client, which may be for a local fake server
+    pub fn new(pool: SqlitePool, api: WarApi) -> Self {
+        Self {
+            pool,
+            api,
+            interval: POLL_INTERVAL,
+            index: SpatialIndex::new(),
+            state: Mutex::new(IngestState::default()),
+            clock: Box::new(|| Utc::now().naive_utc()),
+        }
+    }
+
+    /// Sets the time between each poll; chainable
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets what gives the current time, such as [FakeWarApi::now]; chainable
+    pub fn clock(mut self, clock: impl Fn() -> NaiveDateTime + Send + Sync + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Polls the WarAPI forever, logging rather than stopping on errors so a blip doesn't end ingestion
+    pub async fn run(self) {
+        info!(
+            "Starting WarAPI ingestion from {} every {:?}",
+            self.api.base_url(),
+            self.interval
+        );
+        let mut interval = tokio::time::interval(self.interval);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self.poll().await {
+                warn!("Couldn't ingest from WarAPI, {}", err);
+            }
+        }
+    }
+
+    /// Polls the WarAPI once, recording everything new
+    pub async fn poll(&self) -> Result<()> {
+        if let Some(war_num) = self.poll_war().await? {
+            self.poll_ownership(war_num).await?;
+            self.poll_battles(war_num).await?;
+        }
+        Ok(())
+    }
+
+    /// Current time according to the clock of this ingester
+    fn now(&self) -> NaiveDateTime {
+        (self.clock)()
+    }
+}
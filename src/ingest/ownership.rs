@@ -0,0 +1,88 @@
+//! Recording of who holds each town and relic base from `/worldconquest/maps/{hex}/dynamic/public`
+
+use super::Ingester;
+use crate::map::{Faction, Hex, Location, Map};
+use crate::warapi::{DynamicMap, MapItem};
+use crate::{models::Ownership, Result};
+use log::{info, trace};
+use std::collections::HashMap;
+
+/// Api icon types of town bases and relic bases, which are what locations are held through
+const BASE_ICONS: [i64; 6] = [45, 46, 47, 56, 57, 58];
+
+/// Furthest a base may be from a major location in world coordinates to be counted as holding it
+const MAX_BASE_DISTANCE: f64 = 0.1;
+
+impl Ingester {
+    /// Polls the bases of every hex, storing a new ownership snapshot of a location whenever its team or
+    /// flags change
+    pub(super) async fn poll_ownership(&self, war_num: i64) -> Result<()> {
+        let now = self.now();
+        for hex in Hex::all_in_war(war_num) {
+            let dynamic = self.api.dynamic_map(hex).await?;
+
+            for (map, item) in self.match_bases(hex, war_num, &dynamic) {
+                let team = Faction::from_name_api(&item.team_id);
+                let known = self.state.lock().unwrap().owners.get(&map).copied();
+                let known = match known {
+                    Some(known) => Some(known),
+                    None => Ownership::get_latest(&self.pool, war_num, map)
+                        .await?
+                        .map(|ownership| (ownership.team, ownership.flags)),
+                };
+
+                if known != Some((team, item.flags)) {
+                    match team {
+                        Some(team) => info!("{} is now held by the {}", map, team),
+                        None => info!("{} is no longer held", map),
+                    }
+                    Ownership::new(&self.pool, war_num, map, team, item.flags, now).await?;
+                } else {
+                    trace!("Ownership of {} is unchanged", map);
+                }
+                self.state
+                    .lock()
+                    .unwrap()
+                    .owners
+                    .insert(map, (team, item.flags));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Matches each base within a hex to the closest major location which existed during the war,
+    /// keeping only the closest base to each location
+    fn match_bases<'a>(
+        &self,
+        hex: Hex,
+        war_num: i64,
+        dynamic: &'a DynamicMap,
+    ) -> HashMap<Map, &'a MapItem> {
+        let mut closest: HashMap<Map, (f64, &MapItem)> = HashMap::new();
+        for item in dynamic
+            .map_items
+            .iter()
+            .filter(|item| BASE_ICONS.contains(&item.icon_type))
+        {
+            let found = self
+                .index
+                .nearest_by(hex.to_world((item.x, item.y)), |map| {
+                    map.hex() == hex && map.is_major() && map.existed_in(war_num)
+                });
+            if let Some((map, dist)) = found {
+                let is_closer = match closest.get(&map) {
+                    Some((other, _)) => dist < *other,
+                    None => true,
+                };
+                if dist <= MAX_BASE_DISTANCE && is_closer {
+                    closest.insert(map, (dist, item));
+                }
+            }
+        }
+        closest
+            .into_iter()
+            .map(|(map, (_, item))| (map, item))
+            .collect()
+    }
+}
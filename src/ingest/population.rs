@@ -0,0 +1,98 @@
+//! Population reports of active battles, estimated from the casualties and enlistments of their hex
+
+use super::{Counters, Ingester};
+use crate::map::Hex;
+use crate::models::{Battle, FactionCounts, Population};
+use crate::warapi::WarReport;
+use crate::Result;
+use chrono::prelude::*;
+use log::trace;
+
+/// Time between each population report of an active battle, in minutes
+const REPORT_INTERVAL: i64 = 15;
+
+/// Rough amount of minutes a player on the frontline lives for, used to turn casualties into players
+const LIFESPAN: f64 = 6.0;
+
+impl Ingester {
+    /// Reports the population of the battle being fought within a hex, if there is one and it's due
+    pub(super) async fn report_population(
+        &self,
+        hex: Hex,
+        report: &WarReport,
+        now: NaiveDateTime,
+    ) -> Result<()> {
+        let due = {
+            let mut state = self.state.lock().unwrap();
+            match state.battles.get_mut(&hex) {
+                Some(active) if (now - active.reported.at).num_minutes() >= REPORT_INTERVAL => {
+                    let since = std::mem::replace(&mut active.reported, Counters::new(report, now));
+                    Some((active.id, since))
+                }
+                _ => None,
+            }
+        };
+        let (battle_id, since) = match due {
+            Some(due) => due,
+            None => return Ok(()),
+        };
+
+        let minutes = (now - since.at).num_seconds() as f64 / 60.0;
+        let factions = estimate_factions(
+            FactionCounts::new(
+                report.colonial_casualties - since.casualties.colonials,
+                report.warden_casualties - since.casualties.wardens,
+            ),
+            report.total_enlistments - since.enlistments,
+            minutes,
+        );
+        trace!(
+            "Reporting population of {} colonials and {} wardens for battle of id {}",
+            factions.colonials,
+            factions.wardens,
+            battle_id
+        );
+        Population::new(&self.pool, battle_id, factions.total(), now, factions, None).await?;
+        Battle::update_factions(&self.pool, battle_id, factions).await?;
+        Ok(())
+    }
+}
+
+/// Estimates how many players of each faction are fighting from their casualties and the enlistments
+/// within a hex over some minutes, turning the rate of casualties into players by how long they tend to
+/// live and splitting new enlistments on top by which faction is dying more
+fn estimate_factions(casualties: FactionCounts, enlistments: i64, minutes: f64) -> FactionCounts {
+    if minutes <= 0.0 {
+        return FactionCounts::new(0, 0);
+    }
+    let (colonials, wardens) = (casualties.colonials.max(0), casualties.wardens.max(0));
+    let colonial_share = match colonials + wardens {
+        0 => 0.5,
+        total => colonials as f64 / total as f64,
+    };
+    let estimate = |casualties: i64, share: f64| {
+        let fighting = casualties as f64 / minutes * LIFESPAN;
+        (fighting + enlistments.max(0) as f64 * share).round() as i64
+    };
+    FactionCounts::new(
+        estimate(colonials, colonial_share),
+        estimate(wardens, 1.0 - colonial_share),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates() {
+        let estimate = |colonials, wardens, enlistments, minutes| {
+            estimate_factions(FactionCounts::new(colonials, wardens), enlistments, minutes)
+        };
+        assert_eq!(estimate(100, 50, 0, 15.0), FactionCounts::new(40, 20));
+        assert_eq!(estimate(100, 50, 12, 15.0), FactionCounts::new(48, 24));
+        assert_eq!(estimate(0, 0, 10, 15.0), FactionCounts::new(5, 5));
+        assert_eq!(estimate(-5, -5, -5, 15.0), FactionCounts::new(0, 0));
+        assert_eq!(estimate(100, 10, 10, 0.0), FactionCounts::new(0, 0));
+    }
+}
@@ -0,0 +1,118 @@
+//! Contains [SpikeDetector] for spotting sudden rises in casualties within a hex
+
+use crate::map::Hex;
+use chrono::prelude::*;
+use std::collections::HashMap;
+
+/// Fewest casualties per minute which count as a spike, so quiet hexes don't spike over a few deaths
+const MIN_SPIKE_RATE: f64 = 10.0;
+
+/// How many times the usual rate of casualties a hex has to reach to spike
+const SPIKE_FACTOR: f64 = 3.0;
+
+/// Weight of the newest rate when updating the usual rate of a hex, between `0` and `1`
+const SMOOTHING: f64 = 0.2;
+
+/// Casualties per minute within a hex since it was last recorded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reading {
+    /// Casualties per minute since the last record
+    pub rate: f64,
+    /// Indicates if the rate was sudden compared to the usual rate of the hex
+    pub spike: bool,
+}
+
+/// Last casualty counter of a hex along with its usual rate
+struct HexCasualties {
+    casualties: i64,
+    at: NaiveDateTime,
+    usual_rate: f64,
+}
+
+/// Tracks the casualty counters of each hex over time to spot sudden spikes in fighting
+#[derive(Default)]
+pub struct SpikeDetector {
+    hexes: HashMap<Hex, HexCasualties>,
+}
+
+impl SpikeDetector {
+    /// Creates a new detector which hasn't seen any hexes yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the total casualties of a hex at a time, giving a reading if there's an earlier record to
+    /// compare against
+    pub fn record(&mut self, hex: Hex, casualties: i64, at: NaiveDateTime) -> Option<Reading> {
+        let prev = self.hexes.insert(
+            hex,
+            HexCasualties {
+                casualties,
+                at,
+                usual_rate: 0.0,
+            },
+        )?;
+
+        // counters going backwards means a new war has started
+        let minutes = (at - prev.at).num_seconds() as f64 / 60.0;
+        if casualties < prev.casualties || minutes <= 0.0 {
+            return None;
+        }
+
+        let rate = (casualties - prev.casualties) as f64 / minutes;
+        let spike = rate >= MIN_SPIKE_RATE && rate >= prev.usual_rate * SPIKE_FACTOR;
+        self.hexes.get_mut(&hex).unwrap().usual_rate =
+            prev.usual_rate * (1.0 - SMOOTHING) + rate * SMOOTHING;
+
+        Some(Reading { rate, spike })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minute(num: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, 8, 25)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            + chrono::Duration::minutes(num)
+    }
+
+    #[test]
+    fn spikes() {
+        let mut detector = SpikeDetector::new();
+        assert_eq!(detector.record(Hex::DeadLands, 1000, minute(0)), None);
+
+        // steady trickle of fighting
+        let mut casualties = 1000;
+        for num in 1..20 {
+            casualties += 5;
+            assert!(
+                !detector
+                    .record(Hex::DeadLands, casualties, minute(num))
+                    .unwrap()
+                    .spike
+            );
+        }
+
+        let reading = detector
+            .record(Hex::DeadLands, casualties + 100, minute(20))
+            .unwrap();
+        assert_eq!(reading.rate, 100.0);
+        assert!(reading.spike);
+
+        // quiet hexes are independent and need a minimum amount
+        detector.record(Hex::Stonecradle, 0, minute(0));
+        assert!(
+            !detector
+                .record(Hex::Stonecradle, 5, minute(1))
+                .unwrap()
+                .spike
+        );
+
+        // new war resets counters
+        assert_eq!(detector.record(Hex::DeadLands, 0, minute(21)), None);
+    }
+}
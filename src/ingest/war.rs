@@ -0,0 +1,92 @@
+//! Recording of wars from the `/worldconquest/war` endpoint
+
+use super::Ingester;
+use crate::models::{Battle, War};
+use crate::{Error, Result};
+use chrono::NaiveDateTime;
+use log::{info, trace, warn};
+
+impl Ingester {
+    /// Polls the current war, adding it if it's new and closing it once it's ended; gives the number of
+    /// the war if it's still being fought
+    pub(super) async fn poll_war(&self) -> Result<Option<i64>> {
+        let state = self.api.war().await?;
+        trace!("Polled state of war number {}", state.war_number);
+        let time_start = match state.started()? {
+            Some(time_start) => time_start,
+            None => return Ok(None), // still waiting for the war to begin
+        };
+
+        match (
+            War::get(&self.pool, state.war_number).await?,
+            state.ended()?,
+        ) {
+            (None, None) => {
+                info!("Recording new war number {}", state.war_number);
+                self.warn_missed_ends(state.war_number).await?;
+                War::new_ongoing(&self.pool, state.war_number, time_start).await?;
+                self.end_battles(None).await?;
+            }
+            (None, Some((time_end, colonial_win))) => {
+                info!("Recording already ended war number {}", state.war_number);
+                self.warn_missed_ends(state.war_number).await?;
+                War::new_historic(
+                    &self.pool,
+                    state.war_number,
+                    time_start,
+                    time_end,
+                    colonial_win,
+                )
+                .await?;
+                self.end_battles(None).await?;
+            }
+            (Some(war), Some((time_end, colonial_win))) if war.time_end.is_none() => {
+                info!("Closing ended war number {}", state.war_number);
+                War::update(
+                    &self.pool,
+                    war.num,
+                    Some(Some(time_end)),
+                    Some(Some(colonial_win)),
+                )
+                .await?;
+                self.end_battles(Some(time_end)).await?;
+            }
+            _ => (),
+        }
+
+        Ok(match state.ended()? {
+            Some(_) => None,
+            None => Some(state.war_number),
+        })
+    }
+
+    /// Warns of earlier wars which were missed ending whilst not polling, which are left open rather than
+    /// guessing when they ended and who won
+    async fn warn_missed_ends(&self, war_num: i64) -> Result<()> {
+        for war in War::get_ongoing(&self.pool).await? {
+            if war.num != war_num {
+                warn!(
+                    "Missed the end of war number {}, leaving it open as its end and winner are unknown",
+                    war.num
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Concludes every battle still being followed once the war they're in is over, as they won't be
+    /// polled again, then forgets everything else about that war; battles end along with the war if
+    /// it's known when it did, otherwise when fighting was last seen
+    async fn end_battles(&self, time_end: Option<NaiveDateTime>) -> Result<()> {
+        let battles = std::mem::take(&mut *self.state.lock().unwrap()).battles;
+        for battle in battles.into_values() {
+            info!("Concluding battle of id {} as its war is over", battle.id);
+            let ended_at = time_end.unwrap_or(battle.last_active);
+            match Battle::conclude(&self.pool, battle.id, ended_at, None).await {
+                Ok(()) | Err(Error::BattleConcluded(_)) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+}
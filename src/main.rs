@@ -5,10 +5,14 @@
 #![deny(unsafe_code)]
 #![warn(missing_docs)]
 
+pub mod ingest;
 pub mod map;
 pub mod models;
+pub mod page;
+pub mod render;
 pub mod routes;
 pub mod schemas;
+pub mod warapi;
 
 mod error;
 
@@ -22,10 +26,14 @@ use sqlx::sqlite::SqlitePoolOptions;
 use std::collections::{BTreeMap, HashMap};
 use std::{env, fs::File, process};
 use tera::{self, Tera};
+use warapi::WarApi;
 
 /// Address to bind to
 const BIND_ADDR: (&str, u16) = ("0.0.0.0", 3224);
 
+/// Address to bind the fake WarAPI to when `FAKE_WARAPI` is set
+const FAKE_WARAPI_ADDR: (&str, u16) = ("127.0.0.1", 3225);
+
 #[actix_web::main]
 async fn main() {
     dotenv().ok();
@@ -61,16 +69,42 @@ async fn main() {
         .await
         .expect("Couldn't connect to pool");
 
+    // start ingesting from warapi if asked, or a fake one replaying a scenario
+    if env::var("INGEST").is_ok() || env::var("FAKE_WARAPI").is_ok() {
+        let ingester = match env::var("FAKE_WARAPI") {
+            Ok(path) => {
+                let fake =
+                    ingest::FakeWarApi::open(&path).expect("Couldn't load FAKE_WARAPI scenario");
+                actix_web::rt::spawn(
+                    fake.clone()
+                        .serve(FAKE_WARAPI_ADDR)
+                        .expect("Couldn't bind fake WarAPI server"),
+                );
+                let base_url = env::var("WARAPI_URL").unwrap_or_else(|_| {
+                    format!("http://{}:{}/api", FAKE_WARAPI_ADDR.0, FAKE_WARAPI_ADDR.1)
+                });
+                ingest::Ingester::new(pool.clone(), WarApi::new(base_url)).clock(move || fake.now())
+            }
+            Err(_) => {
+                let base_url =
+                    env::var("WARAPI_URL").unwrap_or_else(|_| warapi::DEFAULT_BASE_URL.to_string());
+                ingest::Ingester::new(pool.clone(), WarApi::new(base_url))
+            }
+        };
+        actix_web::rt::spawn(ingester.run());
+    } else {
+        info!("Not ingesting from WarAPI as neither INGEST nor FAKE_WARAPI is set");
+    }
+
     // init terra
     trace!("Constructing tera templating instance");
-    let mut tera = match Tera::new(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/**/*")) {
+    let tera = match templates() {
         Ok(tera) => tera,
         Err(err) => {
             error!("Could not construct tera templating instance, {}", err);
             process::exit(1)
         }
     };
-    tera.register_function("url_for", make_url_for(route_urls()));
 
     // run actix
     println!("Starting web server at {}..", bind_url()); // on purpose
@@ -100,6 +134,13 @@ fn bind_url() -> String {
     format!("http://{}:{} address", BIND_ADDR.0, BIND_ADDR.1)
 }
 
+/// Loads all templates along with the `url_for` function they use
+fn templates() -> tera::Result<Tera> {
+    let mut tera = Tera::new(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/**/*"))?;
+    tera.register_function("url_for", make_url_for(route_urls()));
+    Ok(tera)
+}
+
 /// Taken from [Tera Docs](https://tera.netlify.app/docs/#introduction), allows `url_for` mapping of templates
 fn make_url_for(urls: BTreeMap<String, String>) -> impl tera::Function {
     Box::new(
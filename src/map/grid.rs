@@ -0,0 +1,131 @@
+//! Hex adjacency and world-space positions, built on top of [Hex::axial]
+//!
+//! Hexes are flat-topped and sit on an axial grid with the `DeadLands` hex at `(0, 0)`, where `q` grows
+//! eastwards and `r` grows south-eastwards; world coordinates follow the same direction as
+//! [Location::coords], growing rightwards and downwards
+
+use super::{Hex, Location, Map};
+
+/// Corner-to-corner width of a single hex, which world coordinates are measured in
+pub const HEX_WIDTH: f64 = 1.0;
+
+/// Flat-to-flat height of a single hex in world coordinates
+pub const HEX_HEIGHT: f64 = HEX_WIDTH * 0.866_025_403_784_438_6; // sqrt(3) / 2
+
+/// Axial offsets to each of the six hexes surrounding another
+const NEIGHBOUR_OFFSETS: [(i32, i32); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+impl Hex {
+    /// Gets the hex at an axial grid position, if there's one there
+    pub fn from_axial(axial: (i32, i32)) -> Option<Self> {
+        Map::hexes().find(|hex| hex.axial() == axial)
+    }
+
+    /// Iterates over every hex which borders this one
+    pub fn neighbours(&self) -> impl Iterator<Item = Hex> {
+        let (q, r) = self.axial();
+        NEIGHBOUR_OFFSETS
+            .iter()
+            .filter_map(move |(dq, dr)| Hex::from_axial((q + dq, r + dr)))
+    }
+
+    /// Checks if the other hex borders this one
+    pub fn is_neighbour(&self, other: Hex) -> bool {
+        self.distance(other) == 1
+    }
+
+    /// Amount of hex borders crossed to get from this hex to the other, assuming there's no gaps
+    pub fn distance(&self, other: Hex) -> u32 {
+        let (q, r) = self.axial();
+        let (other_q, other_r) = other.axial();
+        let (dq, dr) = (q - other_q, r - other_r);
+        ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as u32
+    }
+
+    /// Centre of this hex in world coordinates
+    pub fn world_centre(&self) -> (f64, f64) {
+        let (q, r) = self.axial();
+        let (q, r) = (q as f64, r as f64);
+        (HEX_WIDTH * 0.75 * q, HEX_HEIGHT * (r + q / 2.0))
+    }
+
+    /// Converts in-hex coordinates such as [Location::coords] or those of api map items into world
+    /// coordinates
+    pub fn to_world(&self, coords: (f64, f64)) -> (f64, f64) {
+        let (centre_x, centre_y) = self.world_centre();
+        (
+            centre_x + (coords.0 - 0.5) * HEX_WIDTH,
+            centre_y + (coords.1 - 0.5) * HEX_HEIGHT,
+        )
+    }
+}
+
+impl Map {
+    /// Converts the in-hex [Location::coords] of this location into a global position on the world map
+    pub fn world_coords(&self) -> (f64, f64) {
+        self.hex().to_world(self.coords())
+    }
+
+    /// Straight-line distance to another location in world coordinates
+    pub fn world_distance(&self, other: &Map) -> f64 {
+        let (x, y) = self.world_coords();
+        let (other_x, other_y) = other.world_coords();
+        (x - other_x).hypot(y - other_y)
+    }
+
+    /// Checks if the other location is in a hex which borders the hex of this one
+    pub fn in_neighbouring_hex(&self, other: &Map) -> bool {
+        self.hex().is_neighbour(other.hex())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::{DeadLands, Stonecradle};
+
+    #[test]
+    fn neighbours() {
+        let mut found: Vec<Hex> = Hex::DeadLands.neighbours().collect();
+        found.sort_by_key(|hex| hex.name_api());
+        assert_eq!(
+            found,
+            vec![
+                Hex::CallahansPassage,
+                Hex::DrownedVale,
+                Hex::LinnMercy,
+                Hex::LochMor,
+                Hex::MarbanHol,
+                Hex::UmbralWildwood
+            ]
+        );
+
+        for hex in Map::hexes() {
+            assert!(hex.neighbours().count() > 0);
+            assert!(hex
+                .neighbours()
+                .all(|other| other.neighbours().any(|n| n == hex)));
+        }
+    }
+
+    #[test]
+    fn unique_axial() {
+        for hex in Map::hexes() {
+            assert_eq!(Hex::from_axial(hex.axial()), Some(hex));
+        }
+    }
+
+    #[test]
+    fn world_coords() {
+        let map = Map::DeadLands(DeadLands::TheIronRoad);
+        let (x, y) = map.world_coords();
+        let (local_x, local_y) = map.coords();
+        assert!((x - (local_x - 0.5)).abs() < f64::EPSILON);
+        assert!((y - (local_y - 0.5) * HEX_HEIGHT).abs() < f64::EPSILON);
+
+        let far = Map::Stonecradle(Stonecradle::TheCord);
+        assert!(map.world_distance(&far) > HEX_WIDTH);
+        assert_eq!(Hex::DeadLands.distance(Hex::Stonecradle), 2);
+        assert!(!map.in_neighbouring_hex(&far));
+    }
+}
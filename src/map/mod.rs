@@ -0,0 +1,269 @@
+//! Rich inner-game map bindings; see [Map] for top-level variants
+
+pub mod grid;
+mod region;
+mod search;
+mod spatial;
+pub mod version;
+
+pub use region::{Faction, Region};
+pub use spatial::SpatialIndex;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Separator between the hex and inner location of a full [Map] name, e.g. `Stonecradle:TheCord`; this
+/// canonical form is what's stored in the database and sent over the api
+pub const NAME_SEP: char = ':';
+
+/// Common trait for all map locations of a given hex
+pub trait Location: Sized {
+    /// Gets map location from api-centric name, see [Map::from_name] for the full hex and location form
+    fn from_name(name: &str) -> Result<Self, LocationError>;
+
+    /// Generates information from current details
+    fn info(&self) -> LocationInfo {
+        LocationInfo::new(self)
+    }
+
+    /// Checks if this map location if major or not
+    fn is_major(&self) -> bool;
+
+    /// The `x` and `y` coordinates of this location
+    fn coords(&self) -> (f64, f64);
+
+    /// Provides the api-centric name for the overall hex map tile and inner location
+    fn name_api(&self) -> (&str, &str);
+
+    /// Provides the human-readable name for the overall hex map tile and inner location
+    fn name_friendly(&self) -> (&str, &str);
+}
+
+/// Serializable location information generated from a given [Location] implementation
+#[derive(Serialize, Clone)]
+pub struct LocationInfo {
+    /// Indicates if this location is a major point
+    pub is_major: bool,
+    /// Coordinates of location
+    pub coords: (f64, f64),
+    /// Hex name which is suitable for api usage
+    pub hex_api: String,
+    /// Hex name to display to users
+    pub hex_friendly: String,
+    /// Location name which is suitable for api usage
+    pub name_api: String,
+    /// Location name to display to users
+    pub name_friendly: String,
+}
+
+impl LocationInfo {
+    /// Generates information from given location implementation
+    pub fn new(location: &impl Location) -> Self {
+        let (hex_api, name_api) = location.name_api();
+        let (hex_friendly, name_friendly) = location.name_friendly().to_owned();
+
+        Self {
+            is_major: location.is_major(),
+            coords: location.coords(),
+            hex_api: hex_api.to_string(),
+            hex_friendly: hex_friendly.to_string(),
+            name_api: name_api.to_string(),
+            name_friendly: name_friendly.to_string(),
+        }
+    }
+}
+
+/// Reasons a name couldn't be resolved back into a [Location]
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationError {
+    /// Name wasn't in the `hex` and `location` form, missing [NAME_SEP]
+    Malformed(String),
+    /// Hex part of the name didn't match any known hex
+    UnknownHex(String),
+    /// Hex was known but the inner location wasn't a part of it, in the order of hex then location
+    UnknownLocation(String, String),
+    /// Location is known but wasn't on the map during a war, in the order of full name then war number
+    NotInWar(String, i64),
+}
+
+impl fmt::Display for LocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocationError::Malformed(name) => {
+                write!(
+                    f,
+                    "Name '{}' isn't in the hex{}location form",
+                    name, NAME_SEP
+                )
+            }
+            LocationError::UnknownHex(hex) => write!(f, "Unknown hex '{}'", hex),
+            LocationError::UnknownLocation(hex, location) => {
+                write!(f, "Unknown location '{}' in hex '{}'", location, hex)
+            }
+            LocationError::NotInWar(name, war_num) => {
+                write!(
+                    f,
+                    "Location '{}' wasn't on the map in war {}",
+                    name, war_num
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocationError {}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (hex, location) = self.name_api();
+        write!(f, "{}{}{}", hex, NAME_SEP, location)
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name_api())
+    }
+}
+
+/// Implements the canonical string conversions for hex enums using [Location::from_name] for parsing,
+/// with hex enums displayed as just their inner location; `@parse` skips [fmt::Display] for [Map] and [Hex]
+macro_rules! impl_name_conversions {
+    (@parse $($name:ident),*) => {
+        $(
+            impl FromStr for $name {
+                type Err = LocationError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::from_name(s)
+                }
+            }
+
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer)?
+                        .parse()
+                        .map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+    ($($hex:ident),*) => {
+        $(
+            impl fmt::Display for $hex {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.name_api().1)
+                }
+            }
+        )*
+
+        impl_name_conversions!(@parse $($hex),*);
+    };
+}
+
+impl_name_conversions!(@parse Map, Hex);
+#[cfg(test)]
+pub(crate) use impl_name_conversions;
+
+// generated from every dump in `data/maps/` by the build script, see `build.rs`
+include!(concat!(env!("OUT_DIR"), "/map.rs"));
+
+impl Map {
+    /// Iterates over every location of every hex
+    pub fn all() -> impl Iterator<Item = Map> {
+        Map::ALL.iter().copied()
+    }
+
+    /// Iterates over every hex of the map
+    pub fn hexes() -> impl Iterator<Item = Hex> {
+        Hex::ALL.iter().copied()
+    }
+}
+
+impl Hex {
+    /// Iterates over every location within this hex
+    pub fn locations(&self) -> impl Iterator<Item = Map> {
+        Map::ALL[self.span()].iter().copied()
+    }
+
+    /// Amount of major locations within this hex
+    pub fn count_major(&self) -> usize {
+        self.locations().filter(|map| map.is_major()).count()
+    }
+
+    /// Amount of minor locations within this hex
+    pub fn count_minor(&self) -> usize {
+        self.locations().filter(|map| !map.is_major()).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_form() {
+        let map = Map::Stonecradle(Stonecradle::TheHeirsKnife);
+        assert_eq!(map.to_string(), "Stonecradle:TheHeirsKnife");
+        assert_eq!(Stonecradle::TheHeirsKnife.to_string(), "TheHeirsKnife");
+    }
+
+    #[test]
+    fn roundtrip_str() {
+        for map in Map::all() {
+            assert_eq!(map.to_string().parse(), Ok(map));
+        }
+    }
+
+    #[test]
+    fn roundtrip_serde() {
+        for map in Map::all() {
+            let json = serde_json::to_string(&map).unwrap();
+            assert_eq!(json, format!("\"{}\"", map));
+            assert_eq!(serde_json::from_str::<Map>(&json).unwrap(), map);
+        }
+    }
+
+    #[test]
+    fn hex_locations() {
+        let total: usize = Map::hexes().map(|hex| hex.locations().count()).sum();
+        assert_eq!(total, Map::all().count());
+
+        for hex in Map::hexes() {
+            assert_eq!(hex.to_string().parse(), Ok(hex));
+            assert_eq!(
+                hex.count_major() + hex.count_minor(),
+                hex.locations().count()
+            );
+            assert!(hex.locations().all(|map| map.hex() == hex));
+        }
+        assert_eq!(Hex::Stonecradle.count_major(), 8);
+        assert_eq!(Hex::Stonecradle.count_minor(), 5);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "Stonecradle".parse::<Map>(),
+            Err(LocationError::Malformed("Stonecradle".to_string()))
+        );
+        assert_eq!(
+            "Nowhere:TheCord".parse::<Map>(),
+            Err(LocationError::UnknownHex("Nowhere".to_string()))
+        );
+        assert_eq!(
+            "Stonecradle:Nowhere".parse::<Map>(),
+            Err(LocationError::UnknownLocation(
+                "Stonecradle".to_string(),
+                "Nowhere".to_string()
+            ))
+        );
+    }
+}
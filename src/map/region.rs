@@ -0,0 +1,209 @@
+//! Broad [Region] groupings of hexes along with each [Faction]
+
+use super::{Hex, Map};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+
+/// Home region hexes of each faction, which can't be lost during a war
+const HOMES: [(Hex, Faction); 2] = [
+    (Hex::ReachingTrail, Faction::Warden),
+    (Hex::GreatMarch, Faction::Colonial),
+];
+
+/// One of the two sides fighting a war
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Faction {
+    /// Colonial legion, starting from the south of the map
+    Colonial,
+    /// Warden army, starting from the north of the map
+    Warden,
+}
+
+impl Faction {
+    /// Both factions, colonials first
+    pub const ALL: [Faction; 2] = [Faction::Colonial, Faction::Warden];
+
+    /// Provides the api-centric name for this faction, as used by the api's `teamId`
+    pub fn name_api(&self) -> &'static str {
+        match self {
+            Faction::Colonial => "COLONIALS",
+            Faction::Warden => "WARDENS",
+        }
+    }
+
+    /// Gets faction from its api-centric name, such as the `winner` of a war which may also be `NONE`
+    pub fn from_name_api(name: &str) -> Option<Self> {
+        Faction::ALL
+            .iter()
+            .find(|faction| faction.name_api() == name)
+            .copied()
+    }
+
+    /// Provides the human-readable name for this faction
+    pub fn name_friendly(&self) -> &'static str {
+        match self {
+            Faction::Colonial => "Colonials",
+            Faction::Warden => "Wardens",
+        }
+    }
+
+    /// Gets the opposing faction
+    pub fn opponent(&self) -> Self {
+        match self {
+            Faction::Colonial => Faction::Warden,
+            Faction::Warden => Faction::Colonial,
+        }
+    }
+}
+
+impl fmt::Display for Faction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name_friendly())
+    }
+}
+
+/// Grouping of hexes used to talk about parts of a war rather than single hexes, going from the north of
+/// the map down to the south
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Home region of a faction
+    Home(Faction),
+    /// Hexes between a faction's home region and the frontline, known as the northern front for wardens
+    /// and the southern front for colonials
+    Backline(Faction),
+    /// Contested hexes along the middle of the map
+    Frontline,
+}
+
+impl Region {
+    /// Every region, from the north of the map down to the south
+    pub const ALL: [Region; 5] = [
+        Region::Home(Faction::Warden),
+        Region::Backline(Faction::Warden),
+        Region::Frontline,
+        Region::Backline(Faction::Colonial),
+        Region::Home(Faction::Colonial),
+    ];
+
+    /// Iterates over every hex within this region
+    pub fn hexes(&self) -> impl Iterator<Item = Hex> {
+        let region = *self;
+        Map::hexes().filter(move |hex| hex.region() == region)
+    }
+
+    /// Faction which this region belongs to, if it's not on the frontline
+    pub fn faction(&self) -> Option<Faction> {
+        match self {
+            Region::Home(faction) | Region::Backline(faction) => Some(*faction),
+            Region::Frontline => None,
+        }
+    }
+
+    /// Provides the api-centric name for this region, e.g. `warden-home`
+    pub fn name_api(&self) -> &'static str {
+        match self {
+            Region::Home(Faction::Warden) => "warden-home",
+            Region::Backline(Faction::Warden) => "warden-backline",
+            Region::Frontline => "frontline",
+            Region::Backline(Faction::Colonial) => "colonial-backline",
+            Region::Home(Faction::Colonial) => "colonial-home",
+        }
+    }
+
+    /// Provides the human-readable name for this region, e.g. `Warden Home Region`
+    pub fn name_friendly(&self) -> &'static str {
+        match self {
+            Region::Home(Faction::Warden) => "Warden Home Region",
+            Region::Backline(Faction::Warden) => "Northern Front",
+            Region::Frontline => "Frontline",
+            Region::Backline(Faction::Colonial) => "Southern Front",
+            Region::Home(Faction::Colonial) => "Colonial Home Region",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name_friendly())
+    }
+}
+
+impl Serialize for Region {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name_api())
+    }
+}
+
+impl Hex {
+    /// Gets the region this hex is grouped into, using its home status or otherwise how far north or
+    /// south it is on the world map
+    pub fn region(&self) -> Region {
+        if let Some((_, faction)) = HOMES.iter().find(|(hex, _)| hex == self) {
+            return Region::Home(*faction);
+        }
+
+        // doubled row of the hex centre, so hexes in odd columns are still whole numbers
+        let (q, r) = self.axial();
+        match 2 * r + q {
+            row if row <= -2 => Region::Backline(Faction::Warden),
+            row if row >= 2 => Region::Backline(Faction::Colonial),
+            _ => Region::Frontline,
+        }
+    }
+
+    /// Faction whose home region this hex is, if any
+    pub fn home_of(&self) -> Option<Faction> {
+        match self.region() {
+            Region::Home(faction) => Some(faction),
+            _ => None,
+        }
+    }
+}
+
+impl Map {
+    /// Gets the region this location is grouped into, see [Hex::region]
+    pub fn region(&self) -> Region {
+        self.hex().region()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_hex_grouped() {
+        let total: usize = Region::ALL
+            .iter()
+            .map(|region| region.hexes().count())
+            .sum();
+        assert_eq!(total, Map::hexes().count());
+        for region in Region::ALL.iter() {
+            assert!(region.hexes().count() > 0);
+        }
+    }
+
+    #[test]
+    fn faction_names() {
+        for faction in Faction::ALL.iter() {
+            assert_eq!(Faction::from_name_api(faction.name_api()), Some(*faction));
+        }
+        assert_eq!(Faction::from_name_api("NONE"), None);
+    }
+
+    #[test]
+    fn homes() {
+        assert_eq!(Hex::ReachingTrail.home_of(), Some(Faction::Warden));
+        assert_eq!(Hex::GreatMarch.home_of(), Some(Faction::Colonial));
+        assert_eq!(Hex::DeadLands.region(), Region::Frontline);
+        assert_eq!(
+            Hex::CallahansPassage.region(),
+            Region::Backline(Faction::Warden)
+        );
+        assert_eq!(
+            Hex::UmbralWildwood.region(),
+            Region::Backline(Faction::Colonial)
+        );
+    }
+}
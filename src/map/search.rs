@@ -0,0 +1,142 @@
+//! Fuzzy and case-insensitive location searching, see [Map::search]
+
+use super::{Location, Map};
+
+/// How well a location matched a query, with earlier variants ranking higher
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Tier {
+    Exact,
+    Prefix,
+    Contains,
+    Typo,
+}
+
+impl Map {
+    /// Searches every location by its friendly and api-centric names, returning up to `limit` of the best
+    /// matches first; tolerant of casing, spacing, apostrophes, accents and small typos
+    pub fn search(query: &str, limit: usize) -> Vec<Map> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return vec![];
+        }
+
+        let mut found: Vec<((Tier, usize, usize), Map)> = Map::all()
+            .filter_map(|map| score(&query, &map).map(|score| (score, map)))
+            .collect();
+        found.sort_by_key(|(score, _)| *score);
+        found.into_iter().take(limit).map(|(_, map)| map).collect()
+    }
+}
+
+/// Scores a location against a normalized query, lower is better and none is no match at all
+fn score(query: &str, map: &Map) -> Option<(Tier, usize, usize)> {
+    let ((hex_api, name_api), (_, name_friendly)) = (map.name_api(), map.name_friendly());
+    let candidates = [
+        normalize(name_friendly),
+        normalize(name_api),
+        normalize(&format!("{}{}", hex_api, name_api)),
+    ];
+
+    candidates
+        .iter()
+        .filter_map(|name| {
+            let tier = if name == query {
+                Tier::Exact
+            } else if name.starts_with(query) {
+                Tier::Prefix
+            } else if name.contains(query) {
+                Tier::Contains
+            } else {
+                let dist = substring_distance(query, name);
+                return if dist <= max_typos(query) {
+                    Some((Tier::Typo, dist, name.len()))
+                } else {
+                    None
+                };
+            };
+            Some((tier, 0, name.len()))
+        })
+        .min()
+}
+
+/// Amount of typos allowed for a query of this length
+fn max_typos(query: &str) -> usize {
+    match query.chars().count() {
+        0..4 => 0,
+        len => (len / 4).max(1),
+    }
+}
+
+/// Lowercases and strips anything which isn't a letter or number, folding accents down to ascii
+fn normalize(name: &str) -> String {
+    name.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ä' | 'ã' => 'a',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ò' | 'ó' | 'ô' | 'ö' | 'õ' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            other => other,
+        })
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+/// Smallest edit distance between the query and any part of the text, so typos can be found even when
+/// the query is only a part of a name
+fn substring_distance(query: &str, text: &str) -> usize {
+    let query: Vec<char> = query.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    // starting anywhere in the text is free, hence the zeroed first row
+    let mut prev = vec![0; text.len() + 1];
+    for (i, query_char) in query.iter().enumerate() {
+        let mut cur = vec![i + 1; text.len() + 1];
+        for (j, text_char) in text.iter().enumerate() {
+            let cost = if query_char == text_char { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+
+    prev.into_iter().min().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::{Godcrofts, LochMor, Stonecradle};
+
+    #[test]
+    fn friendly_names() {
+        assert_eq!(
+            Map::search("heirs knife", 5)[0],
+            Map::Stonecradle(Stonecradle::TheHeirsKnife)
+        );
+        assert_eq!(
+            Map::search("Loch Mor", 5)[0],
+            Map::LochMor(LochMor::LochMor)
+        );
+        assert_eq!(
+            Map::search("bagh mor", 5)[0],
+            Map::Godcrofts(Godcrofts::BaghMòr)
+        );
+    }
+
+    #[test]
+    fn typos() {
+        assert_eq!(
+            Map::search("trammel pol", 5)[0],
+            Map::Stonecradle(Stonecradle::TrammelPool)
+        );
+        assert!(Map::search("zzzzzzzz", 5).is_empty());
+    }
+
+    #[test]
+    fn limits() {
+        assert!(Map::search("", 5).is_empty());
+        assert_eq!(Map::search("the", 3).len(), 3);
+    }
+}
@@ -0,0 +1,221 @@
+//! Contains [SpatialIndex] for nearest-location and radius queries over the world map
+
+use super::{Hex, Location, Map};
+use std::collections::HashMap;
+
+/// Width and height of a single cell of the index in world coordinates, see [super::grid]
+const CELL_SIZE: f64 = 0.125;
+
+/// Location within a cell alongside its world coordinates
+type Entry = (Map, (f64, f64));
+
+/// Uniform grid over the world coordinates of every location, used to quickly find locations close to a
+/// point such as an api map item
+pub struct SpatialIndex {
+    cells: HashMap<(i32, i32), Vec<Entry>>,
+    /// Smallest and largest cell which has a location inside of it
+    bounds: ((i32, i32), (i32, i32)),
+}
+
+impl SpatialIndex {
+    /// Builds an index over every known location
+    pub fn new() -> Self {
+        let mut cells: HashMap<(i32, i32), Vec<Entry>> = HashMap::new();
+        let mut bounds = ((i32::MAX, i32::MAX), (i32::MIN, i32::MIN));
+
+        for map in Map::all() {
+            let point = map.world_coords();
+            let cell = cell_of(point);
+            bounds.0 = (bounds.0 .0.min(cell.0), bounds.0 .1.min(cell.1));
+            bounds.1 = (bounds.1 .0.max(cell.0), bounds.1 .1.max(cell.1));
+            cells.entry(cell).or_default().push((map, point));
+        }
+
+        Self { cells, bounds }
+    }
+
+    /// Finds the closest location to a point in world coordinates, along with its distance
+    pub fn nearest(&self, point: (f64, f64)) -> Option<(Map, f64)> {
+        self.nearest_by(point, |_| true)
+    }
+
+    /// Finds the closest location to a point in world coordinates which matches `filter`, along with
+    /// its distance
+    pub fn nearest_by(
+        &self,
+        point: (f64, f64),
+        filter: impl Fn(&Map) -> bool,
+    ) -> Option<(Map, f64)> {
+        // points beyond the grid are searched from its edge, as no location is any closer than that
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds;
+        let (x, y) = cell_of(point);
+        let (x, y) = (x.clamp(min_x, max_x), y.clamp(min_y, max_y));
+        let max_ring = [x - min_x, max_x - x, y - min_y, max_y - y]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0);
+
+        let mut best: Option<(Map, f64)> = None;
+        for ring in 0..=max_ring {
+            // nothing in this ring or beyond can be closer than the best so far
+            if let Some((_, dist)) = best {
+                if dist <= (ring - 1) as f64 * CELL_SIZE {
+                    break;
+                }
+            }
+
+            for cell in ring_cells((x, y), ring) {
+                for (map, other) in self.cells.get(&cell).into_iter().flatten() {
+                    if !filter(map) {
+                        continue;
+                    }
+                    let dist = distance(point, *other);
+                    let closer = match best {
+                        Some((_, best_dist)) => dist < best_dist,
+                        None => true,
+                    };
+                    if closer {
+                        best = Some((*map, dist));
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Finds the closest location inside of a hex to in-hex coordinates, such as those of api map items
+    pub fn nearest_in_hex(&self, hex: Hex, coords: (f64, f64)) -> Option<Map> {
+        self.nearest_by(hex.to_world(coords), |map| map.hex() == hex)
+            .map(|(map, _)| map)
+    }
+
+    /// Finds the closest major location inside of a hex to in-hex coordinates, typically a town or relic
+    /// base
+    pub fn nearest_major_in_hex(&self, hex: Hex, coords: (f64, f64)) -> Option<Map> {
+        self.nearest_by(hex.to_world(coords), |map| {
+            map.hex() == hex && map.is_major()
+        })
+        .map(|(map, _)| map)
+    }
+
+    /// Lists every location within `radius` of a point in world coordinates, closest first
+    pub fn within(&self, point: (f64, f64), radius: f64) -> Vec<(Map, f64)> {
+        let ((low_x, low_y), (high_x, high_y)) = self.bounds;
+        let (min_x, min_y) = cell_of((point.0 - radius, point.1 - radius));
+        let (max_x, max_y) = cell_of((point.0 + radius, point.1 + radius));
+        let (min_x, min_y) = (min_x.max(low_x), min_y.max(low_y));
+        let (max_x, max_y) = (max_x.min(high_x), max_y.min(high_y));
+
+        let mut found = vec![];
+        for cell_x in min_x..=max_x {
+            for cell_y in min_y..=max_y {
+                for (map, other) in self.cells.get(&(cell_x, cell_y)).into_iter().flatten() {
+                    let dist = distance(point, *other);
+                    if dist <= radius {
+                        found.push((*map, dist));
+                    }
+                }
+            }
+        }
+
+        found.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        found
+    }
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gets the cell which a point in world coordinates falls into
+fn cell_of(point: (f64, f64)) -> (i32, i32) {
+    (
+        (point.0 / CELL_SIZE).floor() as i32,
+        (point.1 / CELL_SIZE).floor() as i32,
+    )
+}
+
+/// Iterates over every cell exactly `ring` cells away from the centre cell
+fn ring_cells(centre: (i32, i32), ring: i32) -> impl Iterator<Item = (i32, i32)> {
+    (-ring..=ring)
+        .flat_map(move |dx| (-ring..=ring).map(move |dy| (dx, dy)))
+        .filter(move |(dx, dy)| dx.abs() == ring || dy.abs() == ring)
+        .map(move |(dx, dy)| (centre.0 + dx, centre.1 + dy))
+}
+
+/// Straight-line distance between two points
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::Stonecradle;
+
+    /// Spread of points across and slightly beyond the world map
+    fn sample_points() -> impl Iterator<Item = (f64, f64)> {
+        (-30..30).flat_map(|x| (-25..25).map(move |y| (x as f64 * 0.1, y as f64 * 0.1)))
+    }
+
+    #[test]
+    fn nearest_matches_brute_force() {
+        let index = SpatialIndex::new();
+        for point in sample_points() {
+            let brute = Map::all()
+                .map(|map| distance(point, map.world_coords()))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(index.nearest(point).unwrap().1, brute);
+        }
+    }
+
+    #[test]
+    fn nearest_from_far_away() {
+        let index = SpatialIndex::new();
+        for point in [
+            (f64::MAX, f64::MAX),
+            (f64::MIN, 0.5),
+            (1e12, -1e12),
+            (0.5, f64::MIN),
+        ] {
+            let brute = Map::all()
+                .map(|map| distance(point, map.world_coords()))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(index.nearest(point).unwrap().1, brute);
+            assert!(index.within(point, 1.0).is_empty());
+        }
+        assert_eq!(index.within((0.5, 0.5), f64::MAX).len(), Map::all().count());
+    }
+
+    #[test]
+    fn within_matches_brute_force() {
+        let index = SpatialIndex::new();
+        for point in sample_points().step_by(7) {
+            let brute = Map::all()
+                .filter(|map| distance(point, map.world_coords()) <= 0.3)
+                .count();
+            let found = index.within(point, 0.3);
+            assert_eq!(found.len(), brute);
+            assert!(found.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        }
+    }
+
+    #[test]
+    fn nearest_in_hex() {
+        let index = SpatialIndex::new();
+        let map = Map::Stonecradle(Stonecradle::TheCord);
+        let (x, y) = map.coords();
+        assert_eq!(
+            index.nearest_in_hex(Hex::Stonecradle, (x + 0.01, y - 0.01)),
+            Some(map)
+        );
+        assert!(index
+            .nearest_major_in_hex(Hex::Stonecradle, (0.3, 0.3))
+            .unwrap()
+            .is_major());
+    }
+}
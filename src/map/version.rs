@@ -0,0 +1,167 @@
+//! Versioning of the map between wars, so that locations resolve against the map as it was at the time
+//!
+//! Every location from every version is kept in [Map], meaning a stored location can always be loaded
+//! even after it's removed from the game; these methods narrow that down to what existed during a war
+
+use super::{Hex, Location, LocationError, Map, MAP_VERSIONS};
+
+/// Gets the index into [MAP_VERSIONS] of the map used during a war, with wars before the oldest known
+/// version using that oldest version
+pub fn version_of(war_num: i64) -> usize {
+    version_in(&MAP_VERSIONS, war_num)
+}
+
+/// Gets the index of the version used during a war out of the first wars of each, like [version_of]
+fn version_in(versions: &[i64], war_num: i64) -> usize {
+    versions
+        .iter()
+        .rposition(|first_war| *first_war <= war_num)
+        .unwrap_or(0)
+}
+
+/// Checks if the version bitmask includes the map used during a war, out of the first wars of each
+/// version such as [MAP_VERSIONS]
+fn in_war(versions: &[i64], mask: u64, war_num: i64) -> bool {
+    mask & (1 << version_in(versions, war_num)) != 0
+}
+
+impl Map {
+    /// Checks if this location was on the map during a war
+    pub fn existed_in(&self, war_num: i64) -> bool {
+        in_war(&MAP_VERSIONS, self.version_mask(), war_num)
+    }
+
+    /// Checks if this location is on the newest known map
+    pub fn is_current(&self) -> bool {
+        self.existed_in(i64::MAX)
+    }
+
+    /// Gets map location from its full name like [Map::from_name], but only if it was on the map during
+    /// a war
+    pub fn from_name_in_war(name: &str, war_num: i64) -> Result<Self, LocationError> {
+        let map = Map::from_name(name)?;
+        if map.existed_in(war_num) {
+            Ok(map)
+        } else {
+            Err(LocationError::NotInWar(map.to_string(), war_num))
+        }
+    }
+
+    /// Iterates over every location which was on the map during a war
+    pub fn all_in_war(war_num: i64) -> impl Iterator<Item = Map> {
+        Map::all().filter(move |map| map.existed_in(war_num))
+    }
+}
+
+impl Hex {
+    /// Checks if this hex was on the map during a war
+    pub fn existed_in(&self, war_num: i64) -> bool {
+        in_war(&MAP_VERSIONS, self.version_mask(), war_num)
+    }
+
+    /// Iterates over every hex which was on the map during a war
+    pub fn all_in_war(war_num: i64) -> impl Iterator<Item = Hex> {
+        Map::hexes().filter(move |hex| hex.existed_in(war_num))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Map generated from the made-up dumps in `data/fixtures/maps`, where war `90` adds the
+    /// `CallahansPassage` hex and `DeadLands:SunnyRidge` but removes the `UmbralWildwood` hex and
+    /// `DeadLands:IronJunction`
+    #[allow(dead_code)]
+    mod fixture {
+        use crate::map::{impl_name_conversions, Location, LocationError, NAME_SEP};
+        use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+        use std::{fmt, str::FromStr};
+
+        include!(concat!(env!("OUT_DIR"), "/fixture_map.rs"));
+
+        impl Hex {
+            pub fn mask(&self) -> u64 {
+                self.version_mask()
+            }
+        }
+
+        impl Map {
+            pub fn mask(&self) -> u64 {
+                self.version_mask()
+            }
+        }
+    }
+
+    #[test]
+    fn added_and_removed() {
+        use fixture::{CallahansPassage, DeadLands, Hex, Map, UmbralWildwood, MAP_VERSIONS};
+        assert_eq!(MAP_VERSIONS, [1, 90]);
+
+        // removed hexes and locations come after the ones still on the newest map
+        assert_eq!(
+            Hex::ALL,
+            [Hex::DeadLands, Hex::CallahansPassage, Hex::UmbralWildwood]
+        );
+        assert_eq!(
+            DeadLands::ALL,
+            [
+                DeadLands::ThePits,
+                DeadLands::SunnyRidge,
+                DeadLands::IronJunction
+            ]
+        );
+
+        let hexes = [
+            (Hex::DeadLands.mask(), 0b11),
+            (Hex::UmbralWildwood.mask(), 0b01),
+            (Hex::CallahansPassage.mask(), 0b10),
+        ];
+        let maps = [
+            (Map::DeadLands(DeadLands::ThePits).mask(), 0b11),
+            (Map::DeadLands(DeadLands::IronJunction).mask(), 0b01),
+            (Map::DeadLands(DeadLands::SunnyRidge).mask(), 0b10),
+            (
+                Map::UmbralWildwood(UmbralWildwood::HermitsRest).mask(),
+                0b01,
+            ),
+            (
+                Map::CallahansPassage(CallahansPassage::TheCrumblingPassage).mask(),
+                0b10,
+            ),
+        ];
+        for (mask, expected) in hexes.iter().chain(maps.iter()) {
+            assert_eq!(mask, expected);
+            for (war_num, version) in [(0, 0), (1, 0), (89, 0), (90, 1), (i64::MAX, 1)] {
+                assert_eq!(
+                    in_war(&MAP_VERSIONS, *mask, war_num),
+                    expected & (1 << version) != 0
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn versions_ordered() {
+        assert!(MAP_VERSIONS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(version_of(i64::MIN), 0);
+        assert_eq!(version_of(i64::MAX), MAP_VERSIONS.len() - 1);
+        for (ind, first_war) in MAP_VERSIONS.iter().enumerate() {
+            assert_eq!(version_of(*first_war), ind);
+        }
+    }
+
+    #[test]
+    fn every_location_in_a_version() {
+        for map in Map::all() {
+            assert!(MAP_VERSIONS.iter().any(|war_num| map.existed_in(*war_num)));
+            for war_num in MAP_VERSIONS.iter() {
+                assert!(!map.existed_in(*war_num) || map.hex().existed_in(*war_num));
+            }
+        }
+        for map in Map::all_in_war(i64::MAX) {
+            assert!(map.is_current());
+            assert_eq!(Map::from_name_in_war(&map.to_string(), i64::MAX), Ok(map));
+        }
+    }
+}
@@ -1,16 +1,81 @@
 //! Contains [Battle] and implementations
 
 use crate::map::*;
-use crate::{models::Population, Error, Result};
+use crate::models::{BattleLocation, FactionCounts, Ownership, Population};
+use crate::page::{Cursor, Page};
+use crate::render::{Heatmap, Marker};
+use crate::{Error, Result};
 use chrono::prelude::*;
 use log::trace;
+use serde::Serialize;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 
 const MIN_NAME: usize = 5;
 const MIN_DESCRIPTION: usize = 0;
 const MAX_NAME: usize = 32;
 const MAX_DESCRIPTION: usize = 2000;
 
+/// Whether a battle is still being fought
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BattleStatus {
+    /// Fighting is still going on, so coverage is live
+    Ongoing,
+    /// Fighting has stopped, so coverage is a retrospective
+    Concluded,
+}
+
+impl BattleStatus {
+    /// Provides the name this status is stored as in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BattleStatus::Ongoing => "ongoing",
+            BattleStatus::Concluded => "concluded",
+        }
+    }
+
+    /// Gets status from the name it's stored as, treating anything unknown as ongoing
+    fn from_db(status: &str) -> Self {
+        match status {
+            "concluded" => BattleStatus::Concluded,
+            _ => BattleStatus::Ongoing,
+        }
+    }
+}
+
+/// Criteria for narrowing down battles, see [Battle::get_filtered]; defaults to every battle
+#[derive(Debug, Clone, Default)]
+pub struct BattleFilter {
+    /// Only battles of this war
+    pub war_num: Option<i64>,
+    /// Only battles which were fought within this hex at some point
+    pub hex: Option<Hex>,
+    /// Only battles which were fought over this location at some point
+    pub map: Option<Map>,
+    /// Only battles which started at or after this time
+    pub from: Option<NaiveDateTime>,
+    /// Only battles which started at or before this time
+    pub to: Option<NaiveDateTime>,
+}
+
+/// Row of the `battle` table as stored, converted with [Battle::from_row]
+struct BattleRow {
+    id: i64,
+    war_num: i64,
+    map_location: String,
+    name: Option<String>,
+    description: Option<String>,
+    started_at: Option<NaiveDateTime>,
+    ended_at: Option<NaiveDateTime>,
+    status: String,
+    victor: Option<String>,
+    colonials: Option<i64>,
+    wardens: Option<i64>,
+    last_edited: Option<NaiveDateTime>,
+    submitted: NaiveDateTime,
+}
+
 /// Single battle in the history of foxhole
 pub struct Battle {
     /// Id of battle, autoincremented
@@ -23,33 +88,59 @@ pub struct Battle {
     pub name: Option<String>,
     /// Optional user-submitted description of this battle
     pub description: Option<String>,
+    /// When fighting began, which is when it was submitted for battles from before this was tracked
+    pub started_at: NaiveDateTime,
+    /// When fighting stopped, if it has
+    pub ended_at: Option<NaiveDateTime>,
+    /// Whether this battle is still being fought
+    pub status: BattleStatus,
+    /// Faction which won this battle once concluded, if known
+    pub victor: Option<Faction>,
+    /// Most colonials seen fighting at once, if known
+    pub colonials: Option<i64>,
+    /// Most wardens seen fighting at once, if known
+    pub wardens: Option<i64>,
     /// When the last piece user-submitted content was edited, if any
     pub last_edited: Option<NaiveDateTime>,
     /// Timestamp of when this battle was submitted to the database
     pub submitted: NaiveDateTime,
     /// Population reports of this battle which may be fetched after making this structure
     pub pop_reports: Option<Vec<Population>>,
+    /// Ownership history of this battle's location during its war which may be fetched after making this
+    /// structure
+    pub ownership: Option<Vec<Ownership>>,
+    /// Locations fighting moved onto after starting at `map` which may be fetched after making this
+    /// structure
+    pub locations: Option<Vec<BattleLocation>>,
 }
 
 impl Battle {
-    /// Creates a new battle and adds to database
+    /// Creates a new ongoing battle which started at a time and adds to database, erroring if the location
+    /// wasn't on the map during the war or the name or description are too short or long
     pub async fn new(
         pool: &SqlitePool,
         war_num: i64,
-        map_location: String,
+        map: Map,
+        started_at: NaiveDateTime,
         name: impl Into<Option<String>>,
         description: impl Into<Option<String>>,
     ) -> Result<Self> {
         trace!(
             "Adding new battle with war number {} at location {} from database",
             war_num,
-            map_location
+            map
         );
+        if !map.existed_in(war_num) {
+            return Err(LocationError::NotInWar(map.to_string(), war_num).into());
+        }
+        let map_location = map.to_string();
         let name = name.into();
-        let map = Map::from_name(&map_location).ok_or(Error::LocationNotFound)?;
         let description = description.into();
+        check_len(name.as_deref(), MIN_NAME, MAX_NAME)?;
+        check_len(description.as_deref(), MIN_DESCRIPTION, MAX_DESCRIPTION)?;
         let submitted = Utc::now().naive_utc();
-        let id = sqlx::query!("INSERT INTO battle (war_num, map_location, name, description, submitted) VALUES (?, ?, ?, ?, ?)", war_num, map_location, name, description,submitted).execute(pool).await?.last_insert_rowid();
+        let status = BattleStatus::Ongoing.as_str();
+        let id = sqlx::query!("INSERT INTO battle (war_num, map_location, name, description, started_at, status, submitted) VALUES (?, ?, ?, ?, ?, ?, ?)", war_num, map_location, name, description, started_at, status, submitted).execute(pool).await?.last_insert_rowid();
 
         Ok(Self {
             id,
@@ -57,32 +148,29 @@ impl Battle {
             map,
             name,
             description,
+            started_at,
+            ended_at: None,
+            status: BattleStatus::Ongoing,
+            victor: None,
+            colonials: None,
+            wardens: None,
             last_edited: None,
             submitted,
             pop_reports: None,
+            ownership: None,
+            locations: None,
         })
     }
 
-    /// Attempts to get existing battle from database
+    /// Attempts to get existing battle from database; every version of the map is known, so locations
+    /// removed since its war still load
     pub async fn get(pool: &SqlitePool, id: i64) -> Result<Option<Self>> {
         trace!("Getting battle of id {} from database", id);
-        let opt_record = sqlx::query!("SELECT * FROM battle WHERE id=?", id)
+        sqlx::query_as!(BattleRow, "SELECT * FROM battle WHERE id=?", id)
             .fetch_optional(pool)
-            .await?;
-
-        Ok(match opt_record {
-            Some(record) => Some(Self {
-                id: record.id,
-                war_num: record.war_num,
-                map: Map::from_name(&record.map_location).ok_or(Error::LocationNotFound)?,
-                name: record.name,
-                description: record.description,
-                last_edited: record.last_edited,
-                submitted: record.submitted,
-                pop_reports: None,
-            }),
-            None => None,
-        })
+            .await?
+            .map(Self::from_row)
+            .transpose()
     }
 
     /// Gets battle from database, errors with not found compared to a normal get
@@ -90,28 +178,156 @@ impl Battle {
         Self::get(pool, id).await?.ok_or(Error::BattleNotFound(id))
     }
 
-    /// Gets top posts for homepage, typically ~10 in length; fully gets pop reports
-    pub async fn get_homepage(pool: &SqlitePool) -> Result<Vec<Self>> {
+    /// Gets a page of top posts for homepage from the last day, newest first
+    pub async fn get_homepage(pool: &SqlitePool, page: &Page) -> Result<Vec<Self>> {
         trace!("Getting homepage items from database");
-        sqlx::query!("SELECT * FROM battle WHERE submitted >= datetime('now','-1 day')") // TODO: limit to 10-15
+        let limit = page.limit();
+        let rows = match page.after {
+            Some(cursor) => {
+                sqlx::query_as!(
+                    BattleRow,
+                    "SELECT * FROM battle WHERE submitted >= datetime('now','-1 day')
+                    AND (submitted < ?1 OR (submitted=?1 AND id < ?2))
+                    ORDER BY submitted DESC, id DESC LIMIT ?3",
+                    cursor.submitted,
+                    cursor.id,
+                    limit
+                )
+                .fetch_all(pool)
+                .await?
+            }
+            None => {
+                let offset = page.offset();
+                sqlx::query_as!(
+                    BattleRow,
+                    "SELECT * FROM battle WHERE submitted >= datetime('now','-1 day')
+                    ORDER BY submitted DESC, id DESC LIMIT ? OFFSET ?",
+                    limit,
+                    offset
+                )
+                .fetch_all(pool)
+                .await?
+            }
+        };
+        rows.into_iter().map(Self::from_row).collect()
+    }
+
+    /// Counts the battles in each hex out of every battle the homepage pages through, rather than only
+    /// those on one page
+    pub async fn count_homepage_hexes(pool: &SqlitePool) -> Result<HashMap<Hex, usize>> {
+        trace!("Counting homepage battles in each hex from database");
+        let sep = NAME_SEP.to_string();
+        sqlx::query!(
+            r#"SELECT substr(map_location, 1, instr(map_location, ?) - 1) AS "hex!: String", COUNT(*) AS "count!: i64"
+            FROM battle WHERE submitted >= datetime('now','-1 day') GROUP BY 1"#,
+            sep
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|record| Ok((Hex::from_name(&record.hex)?, record.count as usize)))
+        .collect()
+    }
+
+    /// Gets every battle which took place during a war, latest to start first
+    pub async fn get_war(pool: &SqlitePool, war_num: i64) -> Result<Vec<Self>> {
+        trace!("Getting battles of war number {} from database", war_num);
+        sqlx::query_as!(
+            BattleRow,
+            "SELECT * FROM battle WHERE war_num=? ORDER BY IFNULL(started_at, submitted) DESC, id DESC",
+            war_num
+        )
             .fetch_all(pool)
             .await?
             .into_iter()
-            .map(|record| {
-                Ok(Self {
-                    id: record.id,
-                    war_num: record.war_num,
-                    map: Map::from_name(&record.map_location).ok_or(Error::LocationNotFound)?,
-                    name: record.name,
-                    description: record.description,
-                    last_edited: record.last_edited,
-                    submitted: record.submitted,
-                    pop_reports: None,
-                })
-            })
+            .map(Self::from_row)
             .collect()
     }
 
+    /// Gets a page of battles matching a filter, newest first; battles which moved count for every
+    /// location fought over
+    pub async fn get_filtered(
+        pool: &SqlitePool,
+        filter: &BattleFilter,
+        page: &Page,
+    ) -> Result<Vec<Self>> {
+        trace!("Getting battles matching {:?} from database", filter);
+        let hex_like = filter.hex.map(|hex| format!("{}{}%", hex, NAME_SEP));
+        let map_location = filter.map.map(|map| map.to_string());
+        let limit = page.limit();
+        let rows = match page.after {
+            Some(cursor) => {
+                sqlx::query_as!(
+                    BattleRow,
+                    "SELECT * FROM battle
+                    WHERE (?1 IS NULL OR war_num=?1)
+                    AND (?2 IS NULL OR map_location LIKE ?2 OR id IN (SELECT battle_id FROM battle_location WHERE map_location LIKE ?2))
+                    AND (?3 IS NULL OR map_location=?3 OR id IN (SELECT battle_id FROM battle_location WHERE map_location=?3))
+                    AND (?4 IS NULL OR IFNULL(started_at, submitted) >= ?4)
+                    AND (?5 IS NULL OR IFNULL(started_at, submitted) <= ?5)
+                    AND (submitted < ?6 OR (submitted=?6 AND id < ?7))
+                    ORDER BY submitted DESC, id DESC LIMIT ?8",
+                    filter.war_num,
+                    hex_like,
+                    map_location,
+                    filter.from,
+                    filter.to,
+                    cursor.submitted,
+                    cursor.id,
+                    limit
+                )
+                .fetch_all(pool)
+                .await?
+            }
+            None => {
+                let offset = page.offset();
+                sqlx::query_as!(
+                    BattleRow,
+                    "SELECT * FROM battle
+                    WHERE (?1 IS NULL OR war_num=?1)
+                    AND (?2 IS NULL OR map_location LIKE ?2 OR id IN (SELECT battle_id FROM battle_location WHERE map_location LIKE ?2))
+                    AND (?3 IS NULL OR map_location=?3 OR id IN (SELECT battle_id FROM battle_location WHERE map_location=?3))
+                    AND (?4 IS NULL OR IFNULL(started_at, submitted) >= ?4)
+                    AND (?5 IS NULL OR IFNULL(started_at, submitted) <= ?5)
+                    ORDER BY submitted DESC, id DESC LIMIT ?6 OFFSET ?7",
+                    filter.war_num,
+                    hex_like,
+                    map_location,
+                    filter.from,
+                    filter.to,
+                    limit,
+                    offset
+                )
+                .fetch_all(pool)
+                .await?
+            }
+        };
+        rows.into_iter().map(Self::from_row).collect()
+    }
+
+    /// Converts a row of the `battle` table, leaving everything fetched separately empty; battles from
+    /// before their start was tracked count as starting when submitted
+    fn from_row(row: BattleRow) -> Result<Self> {
+        Ok(Self {
+            id: row.id,
+            war_num: row.war_num,
+            map: Map::from_name(&row.map_location)?,
+            name: row.name,
+            description: row.description,
+            started_at: row.started_at.unwrap_or(row.submitted),
+            ended_at: row.ended_at,
+            status: BattleStatus::from_db(&row.status),
+            victor: row.victor.as_deref().and_then(Faction::from_name_api),
+            colonials: row.colonials,
+            wardens: row.wardens,
+            last_edited: row.last_edited,
+            submitted: row.submitted,
+            pop_reports: None,
+            ownership: None,
+            locations: None,
+        })
+    }
+
     /// Updates provided values to update, does nothing if all values are none
     pub async fn update(
         pool: &SqlitePool,
@@ -120,26 +336,15 @@ impl Battle {
         description: impl Into<Option<String>>,
     ) -> Result<()> {
         trace!("Updating battle of id {} in database", id);
-        // TODO: refactor
         let name = name.into();
         let description = description.into();
+        check_len(name.as_deref(), MIN_NAME, MAX_NAME)?;
+        check_len(description.as_deref(), MIN_DESCRIPTION, MAX_DESCRIPTION)?;
 
         let last_edited = Utc::now().naive_utc();
 
-        if let Some(name_val) = name {
-            let name_len = name_val.len();
-            if name_len > MAX_NAME {
-                return Err(Error::DataTooLong);
-            } else if name_len < MIN_NAME {
-                return Err(Error::DataTooShort);
-            } else if let Some(description_val) = description {
-                let description_len = description_val.len();
-                if description_len > MAX_DESCRIPTION {
-                    return Err(Error::DataTooLong);
-                } else if description_len < MIN_DESCRIPTION {
-                    return Err(Error::DataTooShort);
-                }
-
+        match (name, description) {
+            (Some(name_val), Some(description_val)) => {
                 sqlx::query!(
                     "UPDATE battle SET last_edited=?, name=?, description=? WHERE id=?",
                     last_edited,
@@ -149,7 +354,8 @@ impl Battle {
                 )
                 .execute(pool)
                 .await?;
-            } else {
+            }
+            (Some(name_val), None) => {
                 sqlx::query!(
                     "UPDATE battle SET last_edited=?, name=? WHERE id=?",
                     last_edited,
@@ -159,27 +365,104 @@ impl Battle {
                 .execute(pool)
                 .await?;
             }
-        } else if let Some(description_val) = description {
-            let description_len = description_val.len();
-            if description_len > MAX_DESCRIPTION {
-                return Err(Error::DataTooLong);
-            } else if description_len < MIN_DESCRIPTION {
-                return Err(Error::DataTooShort);
+            (None, Some(description_val)) => {
+                sqlx::query!(
+                    "UPDATE battle SET last_edited=?, description=? WHERE id=?",
+                    last_edited,
+                    description_val,
+                    id
+                )
+                .execute(pool)
+                .await?;
             }
+            (None, None) => (),
+        }
 
-            sqlx::query!(
-                "UPDATE battle SET last_edited=?, description=? WHERE id=?",
-                last_edited,
-                description_val,
-                id
-            )
-            .execute(pool)
+        Ok(())
+    }
+
+    /// Deletes a battle along with its population reports and locations, erroring if it doesn't exist
+    pub async fn delete(pool: &SqlitePool, id: i64) -> Result<()> {
+        trace!("Deleting battle of id {} from database", id);
+        let mut tx = pool.begin().await?;
+        sqlx::query!("DELETE FROM population WHERE battle_id=?", id)
+            .execute(&mut tx)
             .await?;
+        sqlx::query!("DELETE FROM battle_location WHERE battle_id=?", id)
+            .execute(&mut tx)
+            .await?;
+        let deleted = sqlx::query!("DELETE FROM battle WHERE id=?", id)
+            .execute(&mut tx)
+            .await?
+            .rows_affected();
+        if deleted == 0 {
+            return Err(Error::BattleNotFound(id));
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Concludes an ongoing battle, recording when fighting stopped and who won if known; errors if it's
+    /// already concluded or would end before it started
+    pub async fn conclude(
+        pool: &SqlitePool,
+        id: i64,
+        ended_at: NaiveDateTime,
+        victor: Option<Faction>,
+    ) -> Result<()> {
+        trace!("Concluding battle of id {} in database", id);
+        let battle = Self::get_ensure(pool, id).await?;
+        if battle.status == BattleStatus::Concluded {
+            return Err(Error::BattleConcluded(id));
+        } else if ended_at < battle.started_at {
+            return Err(Error::EndedBeforeStart);
         }
 
+        let status = BattleStatus::Concluded.as_str();
+        let victor_api = victor.map(|victor| victor.name_api());
+        sqlx::query!(
+            "UPDATE battle SET ended_at=?, status=?, victor=? WHERE id=?",
+            ended_at,
+            status,
+            victor_api,
+            id
+        )
+        .execute(pool)
+        .await?;
         Ok(())
     }
 
+    /// Raises the most of each faction seen fighting in a battle to the counts given if they're higher
+    pub async fn update_factions(
+        pool: &SqlitePool,
+        id: i64,
+        factions: FactionCounts,
+    ) -> Result<()> {
+        trace!("Updating faction counts of battle of id {} in database", id);
+        sqlx::query!(
+            "UPDATE battle SET colonials=MAX(IFNULL(colonials, 0), ?), wardens=MAX(IFNULL(wardens, 0), ?) WHERE id=?",
+            factions.colonials,
+            factions.wardens,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Most of each faction seen fighting at once, falling back to the largest population report with
+    /// faction counts if this battle doesn't know
+    pub fn factions(&self) -> Option<FactionCounts> {
+        if let (Some(colonials), Some(wardens)) = (self.colonials, self.wardens) {
+            return Some(FactionCounts::new(colonials, wardens));
+        }
+        self.pop_reports
+            .as_ref()?
+            .iter()
+            .filter_map(|report| report.factions())
+            .max_by_key(|factions| factions.total())
+    }
+
     /// Fetches all population reports related to this battles; chainable
     pub async fn get_pop_reports(mut self, pool: &SqlitePool) -> Result<Self> {
         trace!(
@@ -189,7 +472,7 @@ impl Battle {
         self.pop_reports = Some(
             sqlx::query_as!(
                 Population,
-                "SELECT * FROM population WHERE battle_id=?",
+                "SELECT * FROM population WHERE battle_id=? ORDER BY at_time",
                 self.id
             )
             .fetch_all(pool)
@@ -198,9 +481,133 @@ impl Battle {
         Ok(self)
     }
 
+    /// Fetches the population reports of many battles at once, with a single query for each war they
+    /// were fought in rather than for each battle
+    pub async fn get_pop_reports_all(pool: &SqlitePool, battles: Vec<Self>) -> Result<Vec<Self>> {
+        let mut war_nums: Vec<i64> = battles.iter().map(|battle| battle.war_num).collect();
+        war_nums.sort_unstable();
+        war_nums.dedup();
+
+        let mut reports: HashMap<i64, Vec<Population>> = HashMap::new();
+        for war_num in war_nums {
+            for report in Population::get_war(pool, war_num).await? {
+                reports.entry(report.battle_id).or_default().push(report);
+            }
+        }
+
+        Ok(battles
+            .into_iter()
+            .map(|mut battle| {
+                battle.pop_reports = Some(reports.remove(&battle.id).unwrap_or_default());
+                battle
+            })
+            .collect())
+    }
+
+    /// Fetches the ownership history of this battle's location during its war; chainable
+    pub async fn get_ownership(mut self, pool: &SqlitePool) -> Result<Self> {
+        trace!(
+            "Getting ownership history for battle of id {} from database",
+            self.id
+        );
+        self.ownership = Some(Ownership::get_history(pool, self.war_num, self.map).await?);
+        Ok(self)
+    }
+
+    /// Fetches every location fighting moved onto after starting; chainable
+    pub async fn get_locations(mut self, pool: &SqlitePool) -> Result<Self> {
+        trace!(
+            "Getting locations for battle of id {} from database",
+            self.id
+        );
+        self.locations = Some(BattleLocation::get_battle(pool, self.id).await?);
+        Ok(self)
+    }
+
+    /// Adds a location which fighting moved onto, erroring if the location wasn't on the map during the war
+    pub async fn add_location(
+        &self,
+        pool: &SqlitePool,
+        map: Map,
+        at_time: NaiveDateTime,
+    ) -> Result<BattleLocation> {
+        if !map.existed_in(self.war_num) {
+            return Err(LocationError::NotInWar(map.to_string(), self.war_num).into());
+        }
+        BattleLocation::new(pool, self.id, map, at_time).await
+    }
+
+    /// Every location fought over in order along with when fighting reached it, starting with where it
+    /// began; only the starting location if the others haven't been fetched
+    pub fn front(&self) -> Vec<(Map, NaiveDateTime)> {
+        let mut front = vec![(self.map, self.started_at)];
+        front.extend(
+            self.locations
+                .iter()
+                .flatten()
+                .map(|location| (location.map, location.at_time)),
+        );
+        front
+    }
+
+    /// Describes every ownership change of this battle's location since it started, e.g.
+    /// `Wardens captured The Cord`; empty if the ownership history hasn't been fetched
+    pub fn captures(&self) -> Vec<String> {
+        let history = match &self.ownership {
+            Some(history) => history,
+            None => return vec![],
+        };
+        history
+            .iter()
+            .enumerate()
+            .filter(|(_, ownership)| ownership.at_time >= self.started_at)
+            .map(|(ind, ownership)| {
+                ownership.describe(ind.checked_sub(1).map(|prev| &history[prev]))
+            })
+            .collect()
+    }
+
+    /// Cursor pointing to this battle, used for continuing lists of battles after it
+    pub fn cursor(&self) -> Cursor {
+        Cursor::new(self.submitted, self.id)
+    }
+
+    /// Creates a marker for drawing this battle over the map, linking back to its page
+    pub fn marker(&self) -> Marker {
+        let title = match &self.name {
+            Some(name) => name.clone(),
+            None => self.gen_name(),
+        };
+        Marker::new(self.map, title).href(format!("/battle/{}", self.id))
+    }
+
+    /// Adds this battle and any of its fetched population reports to a heatmap; chainable
+    pub fn add_heat(&self, heatmap: Heatmap) -> Heatmap {
+        self.pop_reports
+            .iter()
+            .flatten()
+            .fold(heatmap.add_battle(self.map), |heatmap, report| {
+                heatmap.add_population(self.map, report.counted)
+            })
+    }
+
     /// Generates a battle name automatically if a better one has not been assigned
     pub fn gen_name(&self) -> String {
         let map_name = self.map.name_friendly().1;
+        let last_map = self.front().last().unwrap().0;
+        if last_map != self.map {
+            let last_name = last_map.name_friendly().1;
+            return match &self.pop_reports {
+                Some(reports) if !reports.is_empty() => {
+                    format!("The {} To {} Offensive", map_name, last_name)
+                }
+                _ => format!(
+                    "Breaking: Fighting Rolls From {} To {}",
+                    map_name, last_name
+                ),
+            };
+        }
+
         let size = |num| match num {
             0..30 => "Skirmish",
             30..60 => "Clash",
@@ -220,3 +627,73 @@ impl Battle {
         }
     }
 }
+
+/// Checks the length of optional user-submitted text is within bounds
+fn check_len(value: Option<&str>, min: usize, max: usize) -> Result<()> {
+    match value.map(str::len) {
+        Some(len) if len > max => Err(Error::DataTooLong),
+        Some(len) if len < min => Err(Error::DataTooShort),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{test_pool, War};
+
+    fn ids(battles: &[Battle]) -> Vec<i64> {
+        battles.iter().map(|battle| battle.id).collect()
+    }
+
+    #[test]
+    fn walks_pages() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = test_pool().await;
+            let started_at = NaiveDate::from_ymd_opt(2021, 8, 25)
+                .unwrap()
+                .and_hms_opt(14, 0, 0)
+                .unwrap();
+            War::new_ongoing(&pool, 83, started_at).await.unwrap();
+            let mut newest = vec![];
+            for _ in 0..5 {
+                let map = Map::DeadLands(DeadLands::ThePits);
+                let battle = Battle::new(&pool, 83, map, started_at, None, None)
+                    .await
+                    .unwrap();
+                newest.insert(0, battle.id);
+            }
+            let filter = BattleFilter {
+                war_num: Some(83),
+                ..Default::default()
+            };
+
+            let first = Battle::get_filtered(&pool, &filter, &Page::new(1, 2))
+                .await
+                .unwrap();
+            assert_eq!(ids(&first), newest[..2]);
+            let second = Battle::get_filtered(&pool, &filter, &Page::new(2, 2))
+                .await
+                .unwrap();
+            assert_eq!(ids(&second), newest[2..4]);
+
+            let after = Page::after(first.last().unwrap().cursor(), 2);
+            let second = Battle::get_filtered(&pool, &filter, &after).await.unwrap();
+            assert_eq!(ids(&second), newest[2..4]);
+            let after = Page::after(second.last().unwrap().cursor(), 2);
+            let third = Battle::get_homepage(&pool, &after).await.unwrap();
+            assert_eq!(ids(&third), newest[4..]);
+            assert!(matches!(
+                War::get_all(&pool, &after).await,
+                Err(Error::CursorUnsupported)
+            ));
+
+            // regions are counted over every page rather than only the one shown
+            let counts = Battle::count_homepage_hexes(&pool).await.unwrap();
+            assert_eq!(
+                counts.into_iter().collect::<Vec<_>>(),
+                vec![(Hex::DeadLands, 5)]
+            );
+        })
+    }
+}
@@ -0,0 +1,74 @@
+//! Contains [BattleLocation] and implementations
+
+use crate::map::*;
+use crate::Result;
+use chrono::prelude::*;
+use log::trace;
+use sqlx::SqlitePool;
+
+/// Location which fighting of a battle moved onto after starting at the battle's own location
+pub struct BattleLocation {
+    /// Id of battle location, autoincremented
+    pub id: i64,
+    /// Battle id this corresponds to
+    pub battle_id: i64,
+    /// Location which fighting moved onto
+    pub map: Map,
+    /// When fighting reached this location
+    pub at_time: NaiveDateTime,
+    /// Timestamp of when this was submitted to the database
+    pub submitted: NaiveDateTime,
+}
+
+impl BattleLocation {
+    /// Adds a new location to a battle via battle id, see [crate::models::Battle::add_location] to check
+    /// the location was on the map first
+    pub async fn new(
+        pool: &SqlitePool,
+        battle_id: i64,
+        map: Map,
+        at_time: NaiveDateTime,
+    ) -> Result<Self> {
+        trace!(
+            "Adding new location {} to battle of id {} in database",
+            map,
+            battle_id
+        );
+        let map_location = map.to_string();
+        let submitted = Utc::now().naive_utc();
+        let id = sqlx::query!("INSERT INTO battle_location (battle_id, map_location, at_time, submitted) VALUES (?, ?, ?, ?)", battle_id, map_location, at_time, submitted).execute(pool).await?.last_insert_rowid();
+
+        Ok(Self {
+            id,
+            battle_id,
+            map,
+            at_time,
+            submitted,
+        })
+    }
+
+    /// Gets every location a battle moved onto, oldest first
+    pub async fn get_battle(pool: &SqlitePool, battle_id: i64) -> Result<Vec<Self>> {
+        trace!(
+            "Getting locations of battle of id {} from database",
+            battle_id
+        );
+        sqlx::query!(
+            "SELECT * FROM battle_location WHERE battle_id=? ORDER BY at_time",
+            battle_id
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|record| {
+            Ok(Self {
+                id: record.id,
+                battle_id: record.battle_id,
+                map: Map::from_name(&record.map_location)?,
+                at_time: record.at_time,
+                submitted: record.submitted,
+            })
+        })
+        .collect()
+    }
+}
@@ -1,9 +1,25 @@
 //! Models for interacting with database and programmatic representations of basic constructs
 
 mod battle;
+mod battle_location;
+mod ownership;
 mod population;
 mod war;
 
-pub use battle::Battle;
-pub use population::Population;
+pub use battle::{Battle, BattleFilter, BattleStatus};
+pub use battle_location::BattleLocation;
+pub use ownership::Ownership;
+pub use population::{FactionCounts, Population};
 pub use war::War;
+
+/// Connects to a fresh in-memory database with every migration ran, for testing models against
+#[cfg(test)]
+pub(crate) async fn test_pool() -> sqlx::SqlitePool {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!().run(&pool).await.unwrap();
+    pool
+}
@@ -0,0 +1,129 @@
+//! Contains [Ownership] and implementations
+
+use crate::map::*;
+use crate::Result;
+use chrono::prelude::*;
+use log::trace;
+use sqlx::SqlitePool;
+
+/// Snapshot of who held a town or relic base, only stored when this changes
+pub struct Ownership {
+    /// Id of ownership snapshot, autoincremented
+    pub id: i64,
+    /// Number of war that this snapshot was taken in
+    pub war_num: i64,
+    /// Town or relic base this snapshot is of
+    pub map: Map,
+    /// Faction holding this location, if any
+    pub team: Option<Faction>,
+    /// Api bitflags of this location as given by the WarAPI, such as `0x10` once scorched
+    pub flags: i64,
+    /// When this ownership was first seen
+    pub at_time: NaiveDateTime,
+    /// Timestamp of when this snapshot was submitted to the database
+    pub submitted: NaiveDateTime,
+}
+
+impl Ownership {
+    /// Creates a new ownership snapshot and adds to database
+    pub async fn new(
+        pool: &SqlitePool,
+        war_num: i64,
+        map: Map,
+        team: Option<Faction>,
+        flags: i64,
+        at_time: NaiveDateTime,
+    ) -> Result<Self> {
+        trace!(
+            "Adding new ownership of {} in war number {} to database",
+            map,
+            war_num
+        );
+        let map_location = map.to_string();
+        let team_api = team.map(|team| team.name_api());
+        let submitted = Utc::now().naive_utc();
+        let id = sqlx::query!("INSERT INTO ownership (war_num, map_location, team, flags, at_time, submitted) VALUES (?, ?, ?, ?, ?, ?)", war_num, map_location, team_api, flags, at_time, submitted).execute(pool).await?.last_insert_rowid();
+
+        Ok(Self {
+            id,
+            war_num,
+            map,
+            team,
+            flags,
+            at_time,
+            submitted,
+        })
+    }
+
+    /// Gets the newest known ownership of a location during a war
+    pub async fn get_latest(pool: &SqlitePool, war_num: i64, map: Map) -> Result<Option<Self>> {
+        trace!(
+            "Getting latest ownership of {} in war number {} from database",
+            map,
+            war_num
+        );
+        let map_location = map.to_string();
+        let opt_record = sqlx::query!(
+            "SELECT * FROM ownership WHERE war_num=? AND map_location=? ORDER BY at_time DESC LIMIT 1",
+            war_num,
+            map_location
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(match opt_record {
+            Some(record) => Some(Self {
+                id: record.id,
+                war_num: record.war_num,
+                map,
+                team: record.team.as_deref().and_then(Faction::from_name_api),
+                flags: record.flags,
+                at_time: record.at_time,
+                submitted: record.submitted,
+            }),
+            None => None,
+        })
+    }
+
+    /// Gets every ownership change of a location during a war, oldest first
+    pub async fn get_history(pool: &SqlitePool, war_num: i64, map: Map) -> Result<Vec<Self>> {
+        trace!(
+            "Getting ownership history of {} in war number {} from database",
+            map,
+            war_num
+        );
+        let map_location = map.to_string();
+        Ok(sqlx::query!(
+            "SELECT * FROM ownership WHERE war_num=? AND map_location=? ORDER BY at_time",
+            war_num,
+            map_location
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|record| Self {
+            id: record.id,
+            war_num: record.war_num,
+            map,
+            team: record.team.as_deref().and_then(Faction::from_name_api),
+            flags: record.flags,
+            at_time: record.at_time,
+            submitted: record.submitted,
+        })
+        .collect())
+    }
+
+    /// Describes how this ownership came about compared to who held the location before, e.g.
+    /// `Wardens captured The Cord`
+    pub fn describe(&self, previous: Option<&Ownership>) -> String {
+        let name = self.map.name_friendly().1;
+        let before = previous.and_then(|previous| previous.team);
+        match (before, self.team) {
+            (Some(before), Some(team)) if before != team => format!("{} captured {}", team, name),
+            (None, Some(team)) if previous.is_some() => format!("{} claimed {}", team, name),
+            (_, Some(team)) => format!("{} held {}", team, name),
+            (Some(before), None) => format!("{} lost {}", before, name),
+            (None, None) => format!("Nobody held {}", name),
+        }
+    }
+}
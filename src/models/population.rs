@@ -1,10 +1,17 @@
 //! Contains [Population] and implementations
 
-use crate::Result;
+use crate::map::Faction;
+use crate::{Error, Result};
 use chrono::prelude::*;
 use log::trace;
+use serde::Deserialize;
 use sqlx::{FromRow, SqlitePool};
 
+const MAX_DESCRIPTION: usize = 150;
+
+/// Extended sqlite result code given when inserting a row whose primary key is already taken
+const SQLITE_CONSTRAINT_PRIMARYKEY: &str = "1555";
+
 /// Population report for a given battle, denoting a count for a given time
 #[derive(FromRow)]
 pub struct Population {
@@ -14,6 +21,10 @@ pub struct Population {
     pub counted: i64,
     /// Date at which this count occured, merged with the battle id as a primary key
     pub at_time: NaiveDateTime,
+    /// Amount of colonials counted, adding up to `counted` with `wardens` if known
+    pub colonials: Option<i64>,
+    /// Amount of wardens counted, adding up to `counted` with `colonials` if known
+    pub wardens: Option<i64>,
     /// Optional user-submitted description of this report
     pub description: Option<String>,
     /// When the last piece user-submitted content was edited, if any
@@ -23,12 +34,15 @@ pub struct Population {
 }
 
 impl Population {
-    /// Adds a new population report to database via battle id
+    /// Adds a new population report to database via battle id, erroring if the battle already has a
+    /// report at this time, any count is negative, the factions add up to more than counted or the
+    /// description is too long
     pub async fn new(
         pool: &SqlitePool,
         battle_id: i64,
         counted: i64,
         at_time: NaiveDateTime,
+        factions: impl Into<Option<FactionCounts>>,
         description: impl Into<Option<String>>,
     ) -> Result<Self> {
         trace!(
@@ -36,22 +50,93 @@ impl Population {
             battle_id,
             at_time
         );
+        let factions = factions.into();
+        let negative =
+            matches!(factions, Some(factions) if factions.colonials < 0 || factions.wardens < 0);
+        if counted < 0 || negative {
+            return Err(Error::NegativeCount);
+        } else if matches!(factions, Some(factions) if factions.total() > counted) {
+            return Err(Error::FactionsOverCounted);
+        }
+        let colonials = factions.map(|factions| factions.colonials);
+        let wardens = factions.map(|factions| factions.wardens);
         let description = description.into();
+        check_description(description.as_deref())?;
         let submitted = Utc::now().naive_utc();
 
-        sqlx::query!("INSERT INTO population (battle_id, counted, at_time, description, submitted) VALUES (?, ?, ?, ?, ?)", battle_id, counted, at_time, description, submitted).execute(pool).await?;
+        sqlx::query!("INSERT INTO population (battle_id, counted, at_time, colonials, wardens, description, submitted) VALUES (?, ?, ?, ?, ?, ?, ?)", battle_id, counted, at_time, colonials, wardens, description, submitted).execute(pool).await.map_err(|err| {
+            if is_duplicate(&err) {
+                Error::PopulationExists(battle_id, at_time)
+            } else {
+                err.into()
+            }
+        })?;
 
         Ok(Self {
             battle_id,
             counted,
             at_time,
+            colonials,
+            wardens,
             description,
             last_edited: None,
             submitted,
         })
     }
 
-    /// Updates population report, typically used for descriptions
+    /// Attempts to get existing population report of a battle at a time from database
+    pub async fn get(
+        pool: &SqlitePool,
+        battle_id: i64,
+        at_time: NaiveDateTime,
+    ) -> Result<Option<Self>> {
+        trace!(
+            "Getting pop report with battle id of {} and time of {} from database",
+            battle_id,
+            at_time
+        );
+        Ok(sqlx::query_as!(
+            Self,
+            "SELECT * FROM population WHERE battle_id=? AND at_time=?",
+            battle_id,
+            at_time
+        )
+        .fetch_optional(pool)
+        .await?)
+    }
+
+    /// Gets every population report of every battle of a war at once, oldest first
+    pub async fn get_war(pool: &SqlitePool, war_num: i64) -> Result<Vec<Self>> {
+        trace!(
+            "Getting pop reports of battles of war number {} from database",
+            war_num
+        );
+        Ok(sqlx::query_as!(
+            Self,
+            "SELECT * FROM population WHERE battle_id IN (SELECT id FROM battle WHERE war_num=?) ORDER BY at_time",
+            war_num
+        )
+        .fetch_all(pool)
+        .await?)
+    }
+
+    /// Gets population report from database, errors with not found compared to a normal get
+    pub async fn get_ensure(
+        pool: &SqlitePool,
+        battle_id: i64,
+        at_time: NaiveDateTime,
+    ) -> Result<Self> {
+        Self::get(pool, battle_id, at_time)
+            .await?
+            .ok_or(Error::PopulationNotFound(battle_id, at_time))
+    }
+
+    /// Amount of each faction counted, if known
+    pub fn factions(&self) -> Option<FactionCounts> {
+        Some(FactionCounts::new(self.colonials?, self.wardens?))
+    }
+
+    /// Updates population report, typically used for descriptions; errors if the description is too long
     pub async fn update(
         pool: &SqlitePool,
         battle_id: i64,
@@ -64,6 +149,7 @@ impl Population {
             at_time
         );
         let description = description.into();
+        check_description(description.as_deref())?;
         let last_edited = Utc::now().naive_utc();
 
         if let Some(desc_val) = description {
@@ -81,3 +167,129 @@ impl Population {
         Ok(())
     }
 }
+
+/// Checks an optional user-submitted description fits in the database
+fn check_description(description: Option<&str>) -> Result<()> {
+    match description {
+        Some(description) if description.chars().count() > MAX_DESCRIPTION => {
+            Err(Error::DataTooLong)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks if a database error came from a primary key which was already taken
+fn is_duplicate(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::Database(err) => err.code().as_deref() == Some(SQLITE_CONSTRAINT_PRIMARYKEY),
+        _ => false,
+    }
+}
+
+/// Amount of players from each faction, such as those fighting in a battle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct FactionCounts {
+    /// Amount of colonials
+    pub colonials: i64,
+    /// Amount of wardens
+    pub wardens: i64,
+}
+
+impl FactionCounts {
+    /// Creates new counts for each faction
+    pub fn new(colonials: i64, wardens: i64) -> Self {
+        Self { colonials, wardens }
+    }
+
+    /// Amount of players from a faction
+    pub fn get(&self, faction: Faction) -> i64 {
+        match faction {
+            Faction::Colonial => self.colonials,
+            Faction::Warden => self.wardens,
+        }
+    }
+
+    /// Amount of players from both factions
+    pub fn total(&self) -> i64 {
+        self.colonials + self.wardens
+    }
+
+    /// Rounded percentage of players which are from a faction, with both factions adding up to `100`; an
+    /// even split if nobody was counted
+    pub fn percent(&self, faction: Faction) -> i64 {
+        let colonial = match self.total() {
+            0 => 50,
+            total => (self.colonials as f64 / total as f64 * 100.0).round() as i64,
+        };
+        match faction {
+            Faction::Colonial => colonial,
+            Faction::Warden => 100 - colonial,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::{DeadLands, Map};
+    use crate::models::{test_pool, Battle, War};
+    use crate::schemas::SchemaPopulation;
+
+    #[test]
+    fn validates_reports() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = test_pool().await;
+            let at_time = NaiveDate::from_ymd_opt(2021, 8, 25)
+                .unwrap()
+                .and_hms_opt(14, 0, 0)
+                .unwrap();
+            War::new_ongoing(&pool, 83, at_time).await.unwrap();
+            let map = Map::DeadLands(DeadLands::ThePits);
+            let id = Battle::new(&pool, 83, map, at_time, None, None)
+                .await
+                .unwrap()
+                .id;
+
+            let long = "a".repeat(MAX_DESCRIPTION + 1);
+            assert!(matches!(
+                Population::new(&pool, id, 40, at_time, None, long.clone()).await,
+                Err(Error::DataTooLong)
+            ));
+            assert!(matches!(
+                Population::new(&pool, id, 40, at_time, FactionCounts::new(30, 20), None).await,
+                Err(Error::FactionsOverCounted)
+            ));
+
+            assert!(matches!(
+                Population::new(&pool, id, -1, at_time, None, None).await,
+                Err(Error::NegativeCount)
+            ));
+            assert!(matches!(
+                Population::new(&pool, id, 40, at_time, FactionCounts::new(-5, 20), None).await,
+                Err(Error::NegativeCount)
+            ));
+
+            // limit is in characters rather than bytes
+            let report = Population::new(&pool, id, 40, at_time, None, "é".repeat(MAX_DESCRIPTION))
+                .await
+                .unwrap();
+            assert!(matches!(
+                Population::new(&pool, id, 50, at_time, None, None).await,
+                Err(Error::PopulationExists(battle_id, time)) if battle_id == id && time == at_time
+            ));
+            assert!(matches!(
+                Population::update(&pool, id, at_time, long).await,
+                Err(Error::DataTooLong)
+            ));
+
+            assert_eq!(Population::get_war(&pool, 83).await.unwrap().len(), 1);
+            let battles = Battle::get_war(&pool, 83).await.unwrap();
+            let battles = Battle::get_pop_reports_all(&pool, battles).await.unwrap();
+            assert_eq!(battles[0].pop_reports.as_ref().map(Vec::len), Some(1));
+
+            // timestamps given out must be accepted back when editing a report
+            let schema = SchemaPopulation::from(report);
+            assert_eq!(schema.at_time.parse::<NaiveDateTime>().unwrap(), at_time);
+        })
+    }
+}
@@ -1,7 +1,8 @@
 //! Contains [War] and implementations
 
+use crate::page::Page;
 use crate::{Error, Result};
-use chrono::prelude::*;
+use chrono::{prelude::*, Duration};
 use log::trace;
 use sqlx::{FromRow, SqlitePool};
 
@@ -83,6 +84,65 @@ impl War {
         War::get(pool, num).await?.ok_or(Error::WarNotFound(num))
     }
 
+    /// Gets a page of wars, newest first; wars are ordered by number so are only paged through by
+    /// number, erroring if continuing after a cursor is asked for
+    pub async fn get_all(pool: &SqlitePool, page: &Page) -> Result<Vec<Self>> {
+        trace!("Getting all wars from database");
+        if page.after.is_some() {
+            return Err(Error::CursorUnsupported);
+        }
+        let (limit, offset) = (page.limit(), page.offset());
+        Ok(sqlx::query_as!(
+            Self,
+            "SELECT * FROM war ORDER BY num DESC LIMIT ? OFFSET ?",
+            limit,
+            offset
+        )
+        .fetch_all(pool)
+        .await?)
+    }
+
+    /// Gets every war which hasn't ended yet, typically only the current war
+    pub async fn get_ongoing(pool: &SqlitePool) -> Result<Vec<Self>> {
+        trace!("Getting ongoing wars from database");
+        Ok(
+            sqlx::query_as!(Self, "SELECT * FROM war WHERE time_end IS NULL")
+                .fetch_all(pool)
+                .await?,
+        )
+    }
+
+    /// Counts how many battles were fought during this war
+    pub async fn count_battles(&self, pool: &SqlitePool) -> Result<i64> {
+        trace!("Counting battles of war number {} in database", self.num);
+        Ok(sqlx::query!(
+            r#"SELECT COUNT(*) AS "count: i64" FROM battle WHERE war_num=?"#,
+            self.num
+        )
+        .fetch_one(pool)
+        .await?
+        .count)
+    }
+
+    /// Checks that a war starting at this time either has both an end and victor or neither, and
+    /// doesn't end before it started
+    pub fn check_end(
+        time_start: NaiveDateTime,
+        time_end: Option<NaiveDateTime>,
+        colonial_win: Option<bool>,
+    ) -> Result<()> {
+        match time_end {
+            _ if time_end.is_some() != colonial_win.is_some() => Err(Error::WarEndIncomplete),
+            Some(time_end) if time_end < time_start => Err(Error::EndedBeforeStart),
+            _ => Ok(()),
+        }
+    }
+
+    /// How long this war lasted, or has lasted so far if it's still ongoing
+    pub fn duration(&self) -> Duration {
+        self.time_end.unwrap_or_else(|| Utc::now().naive_utc()) - self.time_start
+    }
+
     /// Updates provided values to update, does nothing if all values are none
     pub async fn update(
         pool: &SqlitePool,
@@ -120,3 +180,31 @@ impl War {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_end() {
+        let start = NaiveDate::from_ymd_opt(2021, 8, 25)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap();
+        let end = start + Duration::days(30);
+        assert!(War::check_end(start, None, None).is_ok());
+        assert!(War::check_end(start, Some(end), Some(true)).is_ok());
+        assert!(matches!(
+            War::check_end(start, Some(end), None),
+            Err(Error::WarEndIncomplete)
+        ));
+        assert!(matches!(
+            War::check_end(start, None, Some(false)),
+            Err(Error::WarEndIncomplete)
+        ));
+        assert!(matches!(
+            War::check_end(end, Some(start), Some(true)),
+            Err(Error::EndedBeforeStart)
+        ));
+    }
+}
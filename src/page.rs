@@ -0,0 +1,199 @@
+//! Contains [Page] and [Cursor] for getting long lists such as battles a slice at a time
+//!
+//! Lists can either be paged through by number with [Page::page], or continued after a [Cursor] to the
+//! last item seen with [Page::after]. Cursors point to a `submitted` timestamp and `id`, so they only
+//! apply to lists ordered by those like battles and stay correct even whilst new items are being added.
+
+use chrono::{DateTime, NaiveDateTime};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// Amount of items in a page if none is asked for
+pub const DEFAULT_LIMIT: u32 = 15;
+
+/// Maximum amount of items which may be asked for in a page
+pub const MAX_LIMIT: u32 = 100;
+
+/// Slice of a list to get, the first [DEFAULT_LIMIT] items by default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+pub struct Page {
+    /// Page number starting from `1`, ignored if continuing after a cursor
+    pub page: Option<u32>,
+    /// Amount of items in this page
+    pub limit: Option<u32>,
+    /// Cursor to the last item seen, so this page starts with the item after it
+    pub after: Option<Cursor>,
+}
+
+impl Page {
+    /// Creates a new page by number, starting from `1`
+    pub fn new(page: u32, limit: u32) -> Self {
+        Self {
+            page: Some(page),
+            limit: Some(limit),
+            after: None,
+        }
+    }
+
+    /// Creates a new page continuing after the item a cursor points to
+    pub fn after(cursor: Cursor, limit: u32) -> Self {
+        Self {
+            page: None,
+            limit: Some(limit),
+            after: Some(cursor),
+        }
+    }
+
+    /// Page number starting from `1`
+    pub fn number(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// Amount of items in this page, kept between `1` and [MAX_LIMIT]
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as i64
+    }
+
+    /// Amount of items to skip before this page, always `0` when continuing after a cursor
+    pub fn offset(&self) -> i64 {
+        match self.after {
+            Some(_) => 0,
+            None => (self.number() - 1) as i64 * self.limit(),
+        }
+    }
+
+    /// Checks if a page of this many items was full, meaning there may be more after it
+    pub fn is_full(&self, count: usize) -> bool {
+        count as i64 >= self.limit()
+    }
+}
+
+/// Position of an item in a list ordered by newest `submitted` then highest `id`, shown as an opaque
+/// string such as `1629900000_500000000_12`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    /// Timestamp of when the item was submitted to the database
+    pub submitted: NaiveDateTime,
+    /// Id of the item, breaking ties between items submitted at the same time
+    pub id: i64,
+}
+
+impl Cursor {
+    /// Creates a new cursor pointing to an item
+    pub fn new(submitted: NaiveDateTime, id: i64) -> Self {
+        Self { submitted, id }
+    }
+}
+
+/// Cursor given wasn't in the form shown by [Cursor]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorError(String);
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid cursor '{}'", self.0)
+    }
+}
+
+impl std::error::Error for CursorError {}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}_{}",
+            self.submitted.and_utc().timestamp(),
+            self.submitted.and_utc().timestamp_subsec_nanos(),
+            self.id
+        )
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = CursorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || CursorError(s.to_string());
+        let parts: Vec<&str> = s.split('_').collect();
+        match parts.as_slice() {
+            [secs, nanos, id] => {
+                let secs = secs.parse().map_err(|_| err())?;
+                let nanos = nanos.parse().map_err(|_| err())?;
+                let submitted = DateTime::from_timestamp(secs, nanos)
+                    .ok_or_else(err)?
+                    .naive_utc();
+                Ok(Self::new(submitted, id.parse().map_err(|_| err())?))
+            }
+            _ => Err(err()),
+        }
+    }
+}
+
+impl Serialize for Cursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cursor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn offsets() {
+        assert_eq!(Page::default().offset(), 0);
+        assert_eq!(Page::default().limit(), DEFAULT_LIMIT as i64);
+        assert_eq!(Page::new(3, 10).offset(), 20);
+        assert_eq!(Page::new(0, 10).offset(), 0);
+        assert_eq!(Page::new(2, 1000).limit(), MAX_LIMIT as i64);
+
+        let cursor = Cursor::new(
+            NaiveDate::from_ymd_opt(2021, 8, 25)
+                .unwrap()
+                .and_hms_opt(14, 0, 0)
+                .unwrap(),
+            12,
+        );
+        assert_eq!(Page::after(cursor, 10).offset(), 0);
+    }
+
+    #[test]
+    fn cursor_roundtrip() {
+        let cursor = Cursor::new(
+            NaiveDate::from_ymd_opt(2021, 8, 25)
+                .unwrap()
+                .and_hms_nano_opt(14, 0, 0, 123_456_789)
+                .unwrap(),
+            12,
+        );
+        assert_eq!(cursor.to_string(), "1629900000_123456789_12");
+        assert_eq!(cursor.to_string().parse(), Ok(cursor));
+        assert!("1629900000_12".parse::<Cursor>().is_err());
+        assert!("a_b_c".parse::<Cursor>().is_err());
+    }
+
+    #[test]
+    fn page_from_query() {
+        let page: Page = serde_json::from_str(r#"{"limit":5,"after":"1629900000_0_3"}"#).unwrap();
+        assert_eq!(page.limit(), 5);
+        assert_eq!(
+            page.after,
+            Some(Cursor::new(
+                NaiveDate::from_ymd_opt(2021, 8, 25)
+                    .unwrap()
+                    .and_hms_opt(14, 0, 0)
+                    .unwrap(),
+                3
+            ))
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Contains [Heatmap] for kernel-density estimates of where fighting took place, drawn by [MapSvg]
+//!
+//! [MapSvg]: super::MapSvg
+
+use crate::map::Map;
+use std::collections::HashMap;
+
+/// Default spread of heat around each location in world coordinates, roughly the distance between two
+/// neighbouring towns
+const BANDWIDTH: f64 = 0.04;
+
+/// Population counted in reports which weighs as much as a single battle
+const POPULATION_PER_BATTLE: f64 = 30.0;
+
+/// Accumulated weight of fighting at each location, typically from battles and their population reports
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    weights: HashMap<Map, f64>,
+    bandwidth: f64,
+}
+
+impl Heatmap {
+    /// Creates a new heatmap without any weight
+    pub fn new() -> Self {
+        Self {
+            weights: HashMap::new(),
+            bandwidth: BANDWIDTH,
+        }
+    }
+
+    /// Sets how far heat spreads around each location in world coordinates; chainable
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    /// Adds weight to a location; chainable
+    pub fn add(mut self, map: Map, weight: f64) -> Self {
+        *self.weights.entry(map).or_default() += weight;
+        self
+    }
+
+    /// Adds a single battle at a location; chainable
+    pub fn add_battle(self, map: Map) -> Self {
+        self.add(map, 1.0)
+    }
+
+    /// Adds a population report of a battle at a location, weighted by the amount counted; chainable
+    pub fn add_population(self, map: Map, counted: i64) -> Self {
+        self.add(map, counted.max(0) as f64 / POPULATION_PER_BATTLE)
+    }
+
+    /// Checks if nothing has been added to this heatmap
+    pub fn is_empty(&self) -> bool {
+        self.weights.values().all(|weight| *weight <= 0.0)
+    }
+
+    /// Total weight added at a location
+    pub fn weight(&self, map: Map) -> f64 {
+        self.weights.get(&map).copied().unwrap_or(0.0)
+    }
+
+    /// Estimates the density of fighting at a point in world coordinates using a gaussian kernel over
+    /// every location, with the bandwidth multiplied by `spread`
+    pub fn density(&self, point: (f64, f64), spread: f64) -> f64 {
+        let bandwidth = self.bandwidth * spread;
+        self.weights
+            .iter()
+            .map(|(map, weight)| {
+                let (x, y) = map.world_coords();
+                let dist_sq = (x - point.0).powi(2) + (y - point.1).powi(2);
+                weight * (-dist_sq / (2.0 * bandwidth * bandwidth)).exp()
+            })
+            .sum()
+    }
+}
+
+impl Default for Heatmap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::{DeadLands, Stonecradle};
+
+    #[test]
+    fn density() {
+        let hot = Map::Stonecradle(Stonecradle::TheCord);
+        let cold = Map::DeadLands(DeadLands::TheIronRoad);
+        let heatmap = Heatmap::new()
+            .add_battle(hot)
+            .add_population(hot, 60)
+            .add_battle(cold);
+
+        assert_eq!(heatmap.weight(hot), 3.0);
+        assert!(
+            heatmap.density(hot.world_coords(), 1.0) > heatmap.density(cold.world_coords(), 1.0)
+        );
+        assert!(heatmap.density((100.0, 100.0), 1.0) < f64::EPSILON);
+        assert!(Heatmap::new().is_empty() && !heatmap.is_empty());
+    }
+}
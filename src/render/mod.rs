@@ -0,0 +1,7 @@
+//! Server-side rendering of the map and the battles on it into images, see [MapSvg]
+
+mod heatmap;
+mod svg;
+
+pub use heatmap::Heatmap;
+pub use svg::{MapSvg, Marker, View};
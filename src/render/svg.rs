@@ -0,0 +1,451 @@
+//! Contains [MapSvg] for drawing a single hex or the whole world map as svg, with markers such as battles
+//! drawn on top
+
+use super::Heatmap;
+use crate::map::grid::{HEX_HEIGHT, HEX_WIDTH};
+use crate::map::{Hex, Location, Map};
+use std::fmt::Write;
+
+/// Pixels per world coordinate, making a single hex 1000 pixels wide
+const SCALE: f64 = 1000.0;
+/// Empty space around the outermost hexes, in pixels
+const PADDING: f64 = 20.0;
+/// Width and height of each cell of a heatmap, in pixels
+const HEAT_CELL: f64 = 20.0;
+/// Smallest heat relative to the hottest cell which is still drawn
+const MIN_HEAT: f64 = 0.05;
+
+/// Colour behind the hexes, matching the insight panels
+const BACKGROUND: &str = "#141618";
+/// Colour of each hex, matching the page background
+const HEX_FILL: &str = "#1d2021";
+/// Colour of hex borders
+const HEX_STROKE: &str = "#565656";
+/// Colour of locations and their names
+const TEXT: &str = "lightgrey";
+/// Colour of markers drawn over the map
+const MARKER: &str = "#b35b97";
+
+/// Part of the map which is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    /// Every hex on the world map, only showing major locations
+    World,
+    /// Single hex along with all of its locations
+    Hex(Hex),
+}
+
+impl View {
+    /// Size multiplier for markers and text, so they're still readable when zoomed out on the world
+    fn detail(&self) -> f64 {
+        match self {
+            View::World => 3.0,
+            View::Hex(_) => 1.0,
+        }
+    }
+}
+
+/// Single point of interest drawn over the map, typically a battle
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marker {
+    /// Location this marker is drawn at
+    pub map: Map,
+    /// Text shown when hovering over this marker
+    pub title: String,
+    /// Link to follow when clicking on this marker, if any
+    pub href: Option<String>,
+    /// Indicates if this marker should stand out from the others, such as the battle being viewed
+    pub highlight: bool,
+}
+
+impl Marker {
+    /// Creates a new marker at a location without a link or highlighting
+    pub fn new(map: Map, title: impl Into<String>) -> Self {
+        Self {
+            map,
+            title: title.into(),
+            href: None,
+            highlight: false,
+        }
+    }
+
+    /// Sets the link of this marker; chainable
+    pub fn href(mut self, href: impl Into<String>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Sets if this marker stands out from the others; chainable
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+}
+
+/// Renderer for a part of the map as svg, made using chained calls before calling [MapSvg::render]
+pub struct MapSvg {
+    view: View,
+    war_num: Option<i64>,
+    markers: Vec<Marker>,
+    heatmap: Option<Heatmap>,
+}
+
+impl MapSvg {
+    /// Creates a new renderer for a part of the map without any markers
+    pub fn new(view: View) -> Self {
+        Self {
+            view,
+            war_num: None,
+            markers: vec![],
+            heatmap: None,
+        }
+    }
+
+    /// Only draws the hexes and locations which were on the map during a war; chainable
+    pub fn in_war(mut self, war_num: i64) -> Self {
+        self.war_num = Some(war_num);
+        self
+    }
+
+    /// Adds a new marker, which is skipped when rendering if it's outside of the view; chainable
+    pub fn add_marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    /// Adds multiple markers, see [MapSvg::add_marker]; chainable
+    pub fn add_markers(mut self, markers: impl IntoIterator<Item = Marker>) -> Self {
+        self.markers.extend(markers);
+        self
+    }
+
+    /// Draws a heatmap over the hexes, underneath any locations and markers; chainable
+    pub fn heatmap(mut self, heatmap: Heatmap) -> Self {
+        self.heatmap = Some(heatmap);
+        self
+    }
+
+    /// Renders into a standalone svg document
+    pub fn render(&self) -> String {
+        let hexes = self.hexes();
+        let detail = self.view.detail();
+        let bounds = bounds(&hexes);
+        let ((min_x, min_y), (max_x, max_y)) = bounds;
+        let (width, height) = (max_x - min_x, max_y - min_y);
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{:.1} {:.1} {:.1} {:.1}\" width=\"{:.0}\" height=\"{:.0}\" font-family=\"Ubuntu Mono, monospace\">",
+            min_x, min_y, width, height, width, height
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>",
+            min_x, min_y, width, height, BACKGROUND
+        )
+        .unwrap();
+
+        for hex in &hexes {
+            self.render_outline(&mut out, *hex);
+        }
+        if let Some(heatmap) = &self.heatmap {
+            render_heat(&mut out, heatmap, &hexes, bounds, detail);
+        }
+        for hex in &hexes {
+            self.render_hex(&mut out, *hex);
+        }
+        for marker in self
+            .markers
+            .iter()
+            .filter(|marker| hexes.contains(&marker.map.hex()))
+        {
+            render_marker(&mut out, marker, detail);
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Gets every hex which is drawn
+    fn hexes(&self) -> Vec<Hex> {
+        match self.view {
+            View::World => Map::hexes()
+                .filter(|hex| match self.war_num {
+                    Some(war_num) => hex.existed_in(war_num),
+                    None => true,
+                })
+                .collect(),
+            View::Hex(hex) => vec![hex],
+        }
+    }
+
+    /// Draws the outline of a hex
+    fn render_outline(&self, out: &mut String, hex: Hex) {
+        writeln!(
+            out,
+            "<polygon points=\"{}\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{:.1}\"><title>{}</title></polygon>",
+            points(hex),
+            HEX_FILL,
+            HEX_STROKE,
+            2.0 * self.view.detail(),
+            escape(hex.name_friendly())
+        )
+        .unwrap();
+    }
+
+    /// Draws the locations within a hex, along with its name when drawing the whole world
+    fn render_hex(&self, out: &mut String, hex: Hex) {
+        let locations = hex.locations().filter(|map| {
+            let existed = match self.war_num {
+                Some(war_num) => map.existed_in(war_num),
+                None => true,
+            };
+            existed && (self.view != View::World || map.is_major())
+        });
+        for map in locations {
+            render_location(out, map, self.view.detail());
+        }
+
+        if self.view == View::World {
+            let (x, y) = scaled(hex.world_centre());
+            writeln!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"60\" fill=\"{}\" text-anchor=\"middle\" opacity=\"0.5\">{}</text>",
+                x,
+                y - HEX_HEIGHT * SCALE * 0.35,
+                TEXT,
+                escape(hex.name_friendly())
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Draws a heatmap as a grid of cells coloured from yellow to red, clipped to the outlines of the hexes
+fn render_heat(
+    out: &mut String,
+    heatmap: &Heatmap,
+    hexes: &[Hex],
+    bounds: ((f64, f64), (f64, f64)),
+    detail: f64,
+) {
+    let cell = HEAT_CELL * detail;
+    let ((min_x, min_y), (max_x, max_y)) = bounds;
+    let (cols, rows) = (
+        ((max_x - min_x) / cell).ceil() as usize,
+        ((max_y - min_y) / cell).ceil() as usize,
+    );
+
+    let cells: Vec<(f64, f64, f64)> = (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| (col, row)))
+        .map(|(col, row)| {
+            let (x, y) = (min_x + col as f64 * cell, min_y + row as f64 * cell);
+            let centre = ((x + cell / 2.0) / SCALE, (y + cell / 2.0) / SCALE);
+            (x, y, heatmap.density(centre, detail))
+        })
+        .collect();
+    let peak = cells
+        .iter()
+        .map(|(_, _, density)| *density)
+        .fold(0.0, f64::max);
+    if peak <= 0.0 {
+        return;
+    }
+
+    out.push_str("<defs><clipPath id=\"hexes\">");
+    for hex in hexes {
+        write!(out, "<polygon points=\"{}\"/>", points(*hex)).unwrap();
+    }
+    out.push_str(
+        "</clipPath></defs>\n<g clip-path=\"url(#hexes)\" shape-rendering=\"crispEdges\">\n",
+    );
+    for (x, y, density) in cells {
+        let heat = density / peak;
+        if heat < MIN_HEAT {
+            continue;
+        }
+        writeln!(
+            out,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"hsl({:.0},100%,50%)\" fill-opacity=\"{:.2}\"/>",
+            x,
+            y,
+            cell,
+            cell,
+            60.0 * (1.0 - heat),
+            heat * 0.75
+        )
+        .unwrap();
+    }
+    out.push_str("</g>\n");
+}
+
+/// Draws a single location as a dot, labelling major locations
+fn render_location(out: &mut String, map: Map, detail: f64) {
+    let (x, y) = scaled(map.world_coords());
+    let name = escape(map.name_friendly().1);
+    let radius = if map.is_major() { 6.0 } else { 3.0 } * detail;
+    writeln!(
+        out,
+        "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"{}\"><title>{}</title></circle>",
+        x, y, radius, TEXT, name
+    )
+    .unwrap();
+    if map.is_major() {
+        writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.1}\" fill=\"{}\" text-anchor=\"middle\">{}</text>",
+            x,
+            y - radius * 2.0,
+            16.0 * detail,
+            TEXT,
+            name
+        )
+        .unwrap();
+    }
+}
+
+/// Draws a single marker over the map, wrapped in a link if it has one
+fn render_marker(out: &mut String, marker: &Marker, detail: f64) {
+    let (x, y) = scaled(marker.map.world_coords());
+    let radius = if marker.highlight { 18.0 } else { 12.0 } * detail;
+    let opacity = if marker.highlight { 1.0 } else { 0.75 };
+
+    if let Some(href) = &marker.href {
+        writeln!(out, "<a href=\"{}\">", escape(href)).unwrap();
+    }
+    writeln!(
+        out,
+        "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"{}\" fill-opacity=\"{}\" stroke=\"{}\" stroke-width=\"{:.1}\"><title>{}</title></circle>",
+        x, y, radius, MARKER, opacity, TEXT, detail, escape(&marker.title)
+    )
+    .unwrap();
+    if marker.href.is_some() {
+        out.push_str("</a>\n");
+    }
+}
+
+/// Gets the six corners of a flat-topped hex in pixels, going clockwise from the east
+fn corners(hex: Hex) -> [(f64, f64); 6] {
+    let (x, y) = scaled(hex.world_centre());
+    let (half_width, half_height) = (HEX_WIDTH * SCALE / 2.0, HEX_HEIGHT * SCALE / 2.0);
+    [
+        (x + half_width, y),
+        (x + half_width / 2.0, y + half_height),
+        (x - half_width / 2.0, y + half_height),
+        (x - half_width, y),
+        (x - half_width / 2.0, y - half_height),
+        (x + half_width / 2.0, y - half_height),
+    ]
+}
+
+/// Formats the corners of a hex for use as polygon points
+fn points(hex: Hex) -> String {
+    let points: Vec<String> = corners(hex)
+        .iter()
+        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+        .collect();
+    points.join(" ")
+}
+
+/// Smallest and largest pixel positions covered by the hexes, including padding
+fn bounds(hexes: &[Hex]) -> ((f64, f64), (f64, f64)) {
+    let mut min = (f64::INFINITY, f64::INFINITY);
+    let mut max = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for (x, y) in hexes.iter().flat_map(|hex| corners(*hex).to_vec()) {
+        min = (min.0.min(x), min.1.min(y));
+        max = (max.0.max(x), max.1.max(y));
+    }
+    if hexes.is_empty() {
+        return ((0.0, 0.0), (PADDING * 2.0, PADDING * 2.0));
+    }
+    (
+        (min.0 - PADDING, min.1 - PADDING),
+        (max.0 + PADDING, max.1 + PADDING),
+    )
+}
+
+/// Converts world coordinates into pixels
+fn scaled(point: (f64, f64)) -> (f64, f64) {
+    (point.0 * SCALE, point.1 * SCALE)
+}
+
+/// Escapes text for use within svg elements and attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::{DeadLands, Stonecradle};
+
+    #[test]
+    fn hex_view() {
+        let inside = Map::Stonecradle(Stonecradle::TheCord);
+        let outside = Map::DeadLands(DeadLands::TheIronRoad);
+        let svg = MapSvg::new(View::Hex(Hex::Stonecradle))
+            .add_marker(Marker::new(inside, "Inside").href("/battle/1"))
+            .add_marker(Marker::new(outside, "Outside"))
+            .render();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        assert_eq!(
+            svg.matches("<circle").count(),
+            Hex::Stonecradle.locations().count() + 1
+        );
+        assert!(svg.contains("<a href=\"/battle/1\">"));
+        assert!(!svg.contains("Outside"));
+    }
+
+    #[test]
+    fn world_view() {
+        let svg = MapSvg::new(View::World).render();
+        assert_eq!(svg.matches("<polygon").count(), Map::hexes().count());
+        assert_eq!(
+            svg.matches("<circle").count(),
+            Map::all().filter(|map| map.is_major()).count()
+        );
+    }
+
+    #[test]
+    fn heatmap() {
+        let map = Map::Stonecradle(Stonecradle::TheCord);
+        let empty = MapSvg::new(View::Hex(Hex::Stonecradle))
+            .heatmap(Heatmap::new())
+            .render();
+        assert!(!empty.contains("clipPath"));
+
+        let svg = MapSvg::new(View::Hex(Hex::Stonecradle))
+            .heatmap(Heatmap::new().add_battle(map))
+            .render();
+        assert!(svg.contains("<clipPath id=\"hexes\">"));
+        assert!(svg.contains("fill=\"hsl(0,100%,50%)\""));
+    }
+
+    #[test]
+    fn escaping() {
+        let svg = MapSvg::new(View::Hex(Hex::Stonecradle))
+            .add_marker(Marker::new(
+                Map::Stonecradle(Stonecradle::TheCord),
+                "<script>\"&'",
+            ))
+            .render();
+        assert!(!svg.contains("<script>"));
+        assert!(svg.contains("&lt;script&gt;&quot;&amp;&#39;"));
+    }
+}
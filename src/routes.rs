@@ -5,12 +5,128 @@ use actix_web::web::ServiceConfig;
 use log::trace;
 
 mod api {
-    use crate::{models::Battle, Result};
-    use actix_web::{patch, web, HttpResponse, Responder};
+    use crate::map::{Faction, Hex, Map};
+    use crate::models::{Battle, BattleFilter, FactionCounts, Population, War};
+    use crate::page::Page;
+    use crate::schemas::{
+        SchemaBattle, SchemaList, SchemaLocation, SchemaPage, SchemaPopulation, SchemaWar,
+    };
+    use crate::{Error, Result};
+    use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
+    use chrono::{NaiveDateTime, Utc};
     use log::info;
-    use serde::Deserialize;
+    use serde::{Deserialize, Deserializer};
     use sqlx::SqlitePool;
 
+    /// Default amount of locations returned from a search
+    const SEARCH_LIMIT: usize = 10;
+    /// Maximum amount of locations which may be requested from a search
+    const MAX_SEARCH_LIMIT: usize = 50;
+
+    #[derive(Deserialize)]
+    pub struct NewBattle {
+        war_num: i64,
+        map: Map,
+        started_at: Option<NaiveDateTime>,
+        name: Option<String>,
+        description: Option<String>,
+    }
+
+    /// Creates a new ongoing battle, starting now if no time is given
+    #[post("/api/battle")]
+    pub async fn post_battle(
+        pool: web::Data<SqlitePool>,
+        new: web::Json<NewBattle>,
+    ) -> Result<impl Responder> {
+        let new = new.into_inner();
+        info!("POST of api battle at {} route", new.map);
+
+        let pool = pool.get_ref();
+        let started_at = new.started_at.unwrap_or_else(|| Utc::now().naive_utc());
+
+        War::get_ensure(pool, new.war_num).await?;
+        let battle = Battle::new(
+            pool,
+            new.war_num,
+            new.map,
+            started_at,
+            new.name,
+            new.description,
+        )
+        .await?;
+        Ok(HttpResponse::Created().json(SchemaBattle::from(battle)))
+    }
+
+    /// Gets battle of id along with its population reports, ownership history and front
+    #[get("/api/battle/{id}")]
+    pub async fn get_battle(
+        pool: web::Data<SqlitePool>,
+        id: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let id = id.into_inner();
+        info!("GET of api battle of id {} route", id);
+
+        let pool = pool.get_ref();
+
+        let battle = Battle::get_ensure(pool, id as i64)
+            .await?
+            .get_pop_reports(pool)
+            .await?
+            .get_ownership(pool)
+            .await?
+            .get_locations(pool)
+            .await?;
+        Ok(HttpResponse::Ok().json(SchemaBattle::from(battle)))
+    }
+
+    #[derive(Deserialize)]
+    pub struct ListBattles {
+        war: Option<i64>,
+        hex: Option<Hex>,
+        location: Option<Map>,
+        from: Option<NaiveDateTime>,
+        to: Option<NaiveDateTime>,
+    }
+
+    /// Lists a page of battles newest first, optionally only those of a war, hex, location or date range
+    #[get("/api/battles")]
+    pub async fn get_battles(
+        pool: web::Data<SqlitePool>,
+        query: web::Query<ListBattles>,
+        page: web::Query<Page>,
+    ) -> Result<impl Responder> {
+        info!("GET of api battles route");
+
+        let pool = pool.get_ref();
+        let filter = BattleFilter {
+            war_num: query.war,
+            hex: query.hex,
+            map: query.location,
+            from: query.from,
+            to: query.to,
+        };
+
+        let battles = Battle::get_filtered(pool, &filter, &page).await?;
+        let page = SchemaPage::new(&page, battles.len(), battles.last().map(Battle::cursor));
+        let items = battles.into_iter().map(SchemaBattle::from).collect();
+        Ok(HttpResponse::Ok().json(SchemaList { items, page }))
+    }
+
+    /// Deletes battle of id along with its population reports
+    #[delete("/api/battle/{id}")]
+    pub async fn delete_battle(
+        pool: web::Data<SqlitePool>,
+        id: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let id = id.into_inner();
+        info!("DELETE of api battle of id {} route", id);
+
+        let pool = pool.get_ref();
+
+        Battle::delete(pool, id as i64).await?;
+        Ok(HttpResponse::NoContent())
+    }
+
     #[derive(Deserialize)]
     pub struct UpdateBattle {
         name: Option<String>,
@@ -33,37 +149,274 @@ mod api {
         Battle::update(pool, id as i64, name, description).await?;
         Ok(HttpResponse::Ok())
     }
+
+    /// Lists a page of wars, newest first
+    #[get("/api/wars")]
+    pub async fn get_wars(
+        pool: web::Data<SqlitePool>,
+        page: web::Query<Page>,
+    ) -> Result<impl Responder> {
+        info!("GET of api wars route");
+
+        let pool = pool.get_ref();
+
+        let wars = War::get_all(pool, &page).await?;
+        let page = SchemaPage::new(&page, wars.len(), None);
+        let items = wars.into_iter().map(SchemaWar::from).collect();
+        Ok(HttpResponse::Ok().json(SchemaList { items, page }))
+    }
+
+    /// Gets war of number along with how many battles were fought during it
+    #[get("/api/war/{num}")]
+    pub async fn get_war(
+        pool: web::Data<SqlitePool>,
+        num: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let num = num.into_inner();
+        info!("GET of api war of num {} route", num);
+
+        let pool = pool.get_ref();
+
+        let war = War::get_ensure(pool, num as i64).await?;
+        let battle_count = war.count_battles(pool).await?;
+        Ok(HttpResponse::Ok().json(SchemaWar::from(war).battle_count(battle_count)))
+    }
+
+    #[derive(Deserialize)]
+    pub struct NewWar {
+        num: i64,
+        time_start: NaiveDateTime,
+        time_end: Option<NaiveDateTime>,
+        colonial_win: Option<bool>,
+    }
+
+    /// Adds a war, which is historic if given both an end time and winner or otherwise ongoing
+    #[post("/api/war")]
+    pub async fn post_war(
+        pool: web::Data<SqlitePool>,
+        new: web::Json<NewWar>,
+    ) -> Result<impl Responder> {
+        info!("POST of api war of num {} route", new.num);
+
+        let pool = pool.get_ref();
+
+        if War::get(pool, new.num).await?.is_some() {
+            return Err(Error::WarExists(new.num));
+        }
+        War::check_end(new.time_start, new.time_end, new.colonial_win)?;
+        let war = match (new.time_end, new.colonial_win) {
+            (Some(time_end), Some(colonial_win)) => {
+                War::new_historic(pool, new.num, new.time_start, time_end, colonial_win).await?
+            }
+            _ => War::new_ongoing(pool, new.num, new.time_start).await?,
+        };
+        Ok(HttpResponse::Created().json(SchemaWar::from(war)))
+    }
+
+    #[derive(Deserialize)]
+    pub struct UpdateWar {
+        #[serde(default, deserialize_with = "some_or_null")]
+        time_end: Option<Option<NaiveDateTime>>,
+        #[serde(default, deserialize_with = "some_or_null")]
+        colonial_win: Option<Option<bool>>,
+    }
+
+    /// Updates war of number, where a field set to `null` is cleared but a missing one is left alone
+    #[patch("/api/war/{num}")]
+    pub async fn patch_war(
+        pool: web::Data<SqlitePool>,
+        update: web::Json<UpdateWar>,
+        num: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let num = num.into_inner() as i64;
+        info!("PATCH of api war of num {} route", num);
+
+        let pool = pool.get_ref();
+
+        let current = War::get_ensure(pool, num).await?;
+        War::check_end(
+            current.time_start,
+            update.time_end.unwrap_or(current.time_end),
+            update.colonial_win.unwrap_or(current.colonial_win),
+        )?;
+        War::update(pool, num, update.time_end, update.colonial_win).await?;
+        Ok(HttpResponse::Ok().json(SchemaWar::from(War::get_ensure(pool, num).await?)))
+    }
+
+    /// Deserializes a present field into [Some] even if it's `null`, so it can be told apart from a
+    /// missing field when used with `#[serde(default)]`
+    fn some_or_null<'de, T, D>(deserializer: D) -> std::result::Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::deserialize(deserializer).map(Some)
+    }
+
+    /// Lists population reports of battle of id, oldest first
+    #[get("/api/battle/{id}/population")]
+    pub async fn get_population(
+        pool: web::Data<SqlitePool>,
+        id: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let id = id.into_inner();
+        info!("GET of api population of battle of id {} route", id);
+
+        let pool = pool.get_ref();
+
+        let battle = Battle::get_ensure(pool, id as i64)
+            .await?
+            .get_pop_reports(pool)
+            .await?;
+        Ok(HttpResponse::Ok().json(SchemaPopulation::from_reports(battle.pop_reports)))
+    }
+
+    #[derive(Deserialize)]
+    pub struct NewPopulation {
+        counted: i64,
+        at_time: Option<NaiveDateTime>,
+        factions: Option<FactionCounts>,
+        description: Option<String>,
+    }
+
+    /// Submits a population report to battle of id, counted now if no time is given
+    #[post("/api/battle/{id}/population")]
+    pub async fn post_population(
+        pool: web::Data<SqlitePool>,
+        new: web::Json<NewPopulation>,
+        id: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let id = id.into_inner() as i64;
+        let new = new.into_inner();
+        info!("POST of api population of battle of id {} route", id);
+
+        let pool = pool.get_ref();
+        let at_time = new.at_time.unwrap_or_else(|| Utc::now().naive_utc());
+
+        Battle::get_ensure(pool, id).await?;
+        let report = Population::new(
+            pool,
+            id,
+            new.counted,
+            at_time,
+            new.factions,
+            new.description,
+        )
+        .await?;
+        if let Some(factions) = new.factions {
+            Battle::update_factions(pool, id, factions).await?;
+        }
+        Ok(HttpResponse::Created().json(SchemaPopulation::from(report)))
+    }
+
+    #[derive(Deserialize)]
+    pub struct UpdatePopulation {
+        description: Option<String>,
+    }
+
+    /// Updates population report of battle of id at a time
+    #[patch("/api/battle/{id}/population/{at_time}")]
+    pub async fn patch_population(
+        pool: web::Data<SqlitePool>,
+        update: web::Json<UpdatePopulation>,
+        path: web::Path<(usize, NaiveDateTime)>,
+    ) -> Result<impl Responder> {
+        let (id, at_time) = path.into_inner();
+        let id = id as i64;
+        info!(
+            "PATCH of api population of battle of id {} at {} route",
+            id, at_time
+        );
+
+        let pool = pool.get_ref();
+
+        Population::get_ensure(pool, id, at_time).await?;
+        Population::update(pool, id, at_time, update.into_inner().description).await?;
+        Ok(HttpResponse::Ok().json(SchemaPopulation::from(
+            Population::get_ensure(pool, id, at_time).await?,
+        )))
+    }
+
+    #[derive(Deserialize)]
+    pub struct SearchLocations {
+        q: String,
+        limit: Option<usize>,
+    }
+
+    /// Searches map locations by name, used for autocompletion
+    #[get("/api/locations")]
+    pub async fn get_locations(search: web::Query<SearchLocations>) -> Result<impl Responder> {
+        info!("GET of api locations with query '{}' route", search.q);
+
+        let limit = search.limit.unwrap_or(SEARCH_LIMIT).min(MAX_SEARCH_LIMIT);
+        let found: Vec<SchemaLocation> = Map::search(&search.q, limit)
+            .into_iter()
+            .map(SchemaLocation::from)
+            .collect();
+        Ok(HttpResponse::Ok().json(found))
+    }
+
+    #[derive(Deserialize)]
+    pub struct ConcludeBattle {
+        ended_at: Option<NaiveDateTime>,
+        victor: Option<Faction>,
+    }
+
+    /// Concludes battle of id, ending now if no time is given
+    #[post("/api/battle/{id}/conclude")]
+    pub async fn conclude_battle(
+        pool: web::Data<SqlitePool>,
+        conclude: web::Json<ConcludeBattle>,
+        id: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let id = id.into_inner();
+        info!("POST of api conclude battle of id {} route", id);
+
+        let pool = pool.get_ref();
+        let ended_at = conclude.ended_at.unwrap_or_else(|| Utc::now().naive_utc());
+
+        Battle::conclude(pool, id as i64, ended_at, conclude.victor).await?;
+        Ok(HttpResponse::Ok())
+    }
 }
 
 mod basic {
-    use crate::models::War;
+    use crate::map::Hex;
+    use crate::models::{BattleFilter, War};
+    use crate::page::Page;
+    use crate::render::{Heatmap, MapSvg, Marker, View};
+    use crate::schemas::SchemaWar;
     use crate::{models::Battle, schemas::Schema, Result};
     use actix_web::web;
     use actix_web::{get, HttpResponse, Responder};
     use log::info;
+    use serde::Deserialize;
     use sqlx::SqlitePool;
 
-    /// Gets homepage
+    /// Gets homepage, optionally continuing after older battles
     #[get("/")]
     pub async fn index(
         pool: web::Data<SqlitePool>,
         tmpl: web::Data<tera::Tera>,
+        page: web::Query<Page>,
     ) -> Result<impl Responder> {
         info!("GET of index/homepage route");
 
         let pool = pool.get_ref();
 
         let tmpl_ctx = Schema::default()
-            .add_battles(Battle::get_homepage(pool).await?)
+            .add_battles(Battle::get_homepage(pool, &page).await?)
+            .page_battles(&page)
             .wars_from_battles(pool)
             .await?
+            .add_regions(&Battle::count_homepage_hexes(pool).await?)
             .to_tmpl_ctx();
         Ok(HttpResponse::Ok().body(tmpl.render("index.html", &tmpl_ctx)?))
     }
 
     /// Gets battle of id
     #[get("/battle/{id}")]
-    pub async fn battle(
+    pub async fn battle_page(
         pool: web::Data<SqlitePool>,
         tmpl: web::Data<tera::Tera>,
         id: web::Path<usize>,
@@ -74,27 +427,158 @@ mod basic {
         let pool = pool.get_ref();
 
         let tmpl_ctx = Schema::default()
-            .add_battle(Battle::get_ensure(pool, id as i64).await?)
+            .add_battle(
+                Battle::get_ensure(pool, id as i64)
+                    .await?
+                    .get_pop_reports(pool)
+                    .await?
+                    .get_ownership(pool)
+                    .await?
+                    .get_locations(pool)
+                    .await?,
+            )
             .wars_from_battles(pool)
             .await?
             .to_tmpl_ctx();
         Ok(HttpResponse::Ok().body(tmpl.render("battle.html", &tmpl_ctx)?))
     }
 
-    /// Gets war of number
+    #[derive(Deserialize)]
+    pub struct MapQuery {
+        war: Option<i64>,
+        highlight: Option<i64>,
+        heatmap: Option<bool>,
+    }
+
+    /// Gets svg of a hex or the whole `world`, with battles of a war or otherwise recent battles drawn
+    /// on top
+    #[get("/map/{name}.svg")]
+    pub async fn map_svg(
+        pool: web::Data<SqlitePool>,
+        name: web::Path<String>,
+        query: web::Query<MapQuery>,
+    ) -> Result<impl Responder> {
+        let name = name.into_inner();
+        info!("GET of map svg of {} route", name);
+
+        let view = match name.as_str() {
+            "world" => View::World,
+            hex => View::Hex(Hex::from_name(hex)?),
+        };
+        render_map(
+            pool.get_ref(),
+            view,
+            query.war,
+            query.highlight,
+            query.heatmap.unwrap_or(false),
+        )
+        .await
+    }
+
+    /// Gets heatmap svg of the hex a battle took place in, alongside the other battles of its war
+    #[get("/battle/{id}/heatmap.svg")]
+    pub async fn battle_heatmap(
+        pool: web::Data<SqlitePool>,
+        id: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let id = id.into_inner();
+        info!("GET of heatmap of battle of id {} route", id);
+
+        let pool = pool.get_ref();
+
+        let battle = Battle::get_ensure(pool, id as i64).await?;
+        render_map(
+            pool,
+            View::Hex(battle.map.hex()),
+            Some(battle.war_num),
+            Some(battle.id),
+            true,
+        )
+        .await
+    }
+
+    /// Gets heatmap svg of the whole world for every battle of a war
+    #[get("/war/{num}/heatmap.svg")]
+    pub async fn war_heatmap(
+        pool: web::Data<SqlitePool>,
+        num: web::Path<usize>,
+    ) -> Result<impl Responder> {
+        let num = num.into_inner();
+        info!("GET of heatmap of war of num {} route", num);
+
+        let pool = pool.get_ref();
+
+        let war = War::get_ensure(pool, num as i64).await?;
+        render_map(pool, View::World, Some(war.num), None, true).await
+    }
+
+    /// Renders part of the map as svg with battles of a war or otherwise recent battles drawn on top,
+    /// optionally with a heatmap of them underneath
+    async fn render_map(
+        pool: &SqlitePool,
+        view: View,
+        war: Option<i64>,
+        highlight: Option<i64>,
+        heatmap: bool,
+    ) -> Result<HttpResponse> {
+        let (mut svg, battles) = match war {
+            Some(war_num) => (
+                MapSvg::new(view).in_war(war_num),
+                Battle::get_war(pool, war_num).await?,
+            ),
+            None => (
+                MapSvg::new(view),
+                Battle::get_homepage(pool, &Page::default()).await?,
+            ),
+        };
+
+        let markers: Vec<Marker> = battles
+            .iter()
+            .map(|battle| battle.marker().highlight(Some(battle.id) == highlight))
+            .collect();
+
+        if heatmap {
+            let battles = battles.into_iter().filter(|battle| match view {
+                View::Hex(hex) => battle.map.hex() == hex,
+                View::World => true,
+            });
+            let heat = Battle::get_pop_reports_all(pool, battles.collect())
+                .await?
+                .iter()
+                .fold(Heatmap::new(), |heat, battle| battle.add_heat(heat));
+            svg = svg.heatmap(heat);
+        }
+
+        Ok(HttpResponse::Ok()
+            .content_type("image/svg+xml")
+            .body(svg.add_markers(markers).render()))
+    }
+
+    /// Gets war of number with a timeline of its battles, optionally continuing after older battles
     #[get("/war/{num}")]
-    pub async fn war(
+    pub async fn war_page(
         pool: web::Data<SqlitePool>,
         tmpl: web::Data<tera::Tera>,
         num: web::Path<usize>,
+        page: web::Query<Page>,
     ) -> Result<impl Responder> {
         let num = num.into_inner();
         info!("GET of war of num {} route", num);
 
         let pool = pool.get_ref();
 
+        let war = War::get_ensure(pool, num as i64).await?;
+        let battle_count = war.count_battles(pool).await?;
+        let filter = BattleFilter {
+            war_num: Some(war.num),
+            ..Default::default()
+        };
+        let battles = Battle::get_filtered(pool, &filter, &page).await?;
+
         let tmpl_ctx = Schema::default()
-            .add_war(War::get_ensure(pool, num as i64).await?)
+            .add_timeline(&war, battles)
+            .page_battles(&page)
+            .add_war(SchemaWar::from(war).battle_count(battle_count))
             .to_tmpl_ctx();
         Ok(HttpResponse::Ok().body(tmpl.render("war.html", &tmpl_ctx)?))
     }
@@ -103,10 +587,26 @@ mod basic {
 /// Factories all routes together for an actix app
 pub fn init(cfg: &mut ServiceConfig) {
     trace!("Configuring typical routes");
+    cfg.service(api::post_battle);
+    cfg.service(api::get_battle);
+    cfg.service(api::get_battles);
     cfg.service(api::patch_battle);
+    cfg.service(api::delete_battle);
+    cfg.service(api::get_population);
+    cfg.service(api::post_population);
+    cfg.service(api::patch_population);
+    cfg.service(api::get_wars);
+    cfg.service(api::get_war);
+    cfg.service(api::post_war);
+    cfg.service(api::patch_war);
+    cfg.service(api::get_locations);
+    cfg.service(api::conclude_battle);
     cfg.service(basic::index);
-    cfg.service(basic::battle);
-    cfg.service(basic::war);
+    cfg.service(basic::battle_page);
+    cfg.service(basic::war_page);
+    cfg.service(basic::map_svg);
+    cfg.service(basic::battle_heatmap);
+    cfg.service(basic::war_heatmap);
 
     trace!("Configuring static file routes");
     cfg.service(
@@ -115,3 +615,52 @@ pub fn init(cfg: &mut ServiceConfig) {
             .prefer_utf8(true),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::{DeadLands, Map};
+    use crate::models::{test_pool, Battle, War};
+    use actix_web::test::{call_service, init_service, read_body, TestRequest};
+    use actix_web::{web::Data, App};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn war_page_is_paged() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = test_pool().await;
+            let at_time = NaiveDate::from_ymd_opt(2021, 8, 25)
+                .unwrap()
+                .and_hms_opt(14, 0, 0)
+                .unwrap();
+            War::new_ongoing(&pool, 83, at_time).await.unwrap();
+            let map = Map::DeadLands(DeadLands::ThePits);
+            let mut ids = vec![];
+            for _ in 0..crate::page::DEFAULT_LIMIT + 3 {
+                ids.push(
+                    Battle::new(&pool, 83, map, at_time, None, None)
+                        .await
+                        .unwrap()
+                        .id,
+                );
+            }
+
+            let app = init_service(
+                App::new()
+                    .app_data(Data::new(crate::templates().unwrap()))
+                    .app_data(Data::new(pool))
+                    .configure(init),
+            )
+            .await;
+            let req = TestRequest::get().uri("/war/83").to_request();
+            let body = read_body(call_service(&app, req).await).await;
+            let body = String::from_utf8(body.to_vec()).unwrap();
+
+            let shown = |id: &i64| body.contains(&format!("&#x2F;battle&#x2F;{}\"", id));
+            let (older, newest) = ids.split_at(3);
+            assert!(newest.iter().all(shown));
+            assert!(!older.iter().any(shown));
+            assert!(body.contains("/war/83?after="));
+        })
+    }
+}
@@ -1,11 +1,13 @@
 //! Serde-based schemas to convert models such as battles into
 
+use std::collections::HashMap;
 use std::time::Duration;
 
-use crate::map::{Location, LocationInfo};
-use crate::models::{Battle, Population, War};
+use crate::map::{Faction, Hex, Location, LocationInfo, Map, Region};
+use crate::models::{Battle, BattleStatus, FactionCounts, Population, War};
+use crate::page::{Cursor, Page};
 use crate::Result;
-use chrono::{NaiveDateTime, Utc};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
 use log::trace;
 use serde::Serialize;
 use sqlx::SqlitePool;
@@ -16,13 +18,16 @@ use sqlx::SqlitePool;
 pub struct Schema {
     pub wars: Option<Vec<SchemaWar>>,
     pub battles: Option<Vec<SchemaBattle>>,
+    pub regions: Option<Vec<SchemaRegion>>,
+    pub page: Option<SchemaPage>,
+    pub timeline: Option<Vec<SchemaDay>>,
 }
 
 impl Schema {
-    /// Converts and adds a new war model
-    pub fn add_war(mut self, war: War) -> Self {
+    /// Converts and adds a new war model, or adds an already converted one
+    pub fn add_war(mut self, war: impl Into<SchemaWar>) -> Self {
         trace!("Adding war to schema");
-        let new_war = SchemaWar::from(war);
+        let new_war = war.into();
         match &mut self.wars {
             Some(wars) => wars.push(new_war),
             None => self.wars = Some(vec![new_war]),
@@ -66,6 +71,61 @@ impl Schema {
         self
     }
 
+    /// Converts and adds battles of a war, also grouping them into a timeline by the day they started on
+    /// and then the hex they started in, newest day first
+    pub fn add_timeline(mut self, war: &War, battles: Vec<Battle>) -> Self {
+        trace!("Adding battles of war to schema as a timeline");
+        let mut grouped: Vec<(NaiveDateTime, Hex, SchemaBattle)> = battles
+            .into_iter()
+            .map(|battle| {
+                (
+                    battle.started_at,
+                    battle.map.hex(),
+                    SchemaBattle::from(battle),
+                )
+            })
+            .collect();
+        let mapped: Vec<SchemaBattle> = grouped
+            .iter()
+            .map(|(_, _, battle)| battle.clone())
+            .collect();
+        grouped.sort_by(|(a_start, a_hex, _), (b_start, b_hex, _)| {
+            b_start
+                .date()
+                .cmp(&a_start.date())
+                .then(a_hex.name_friendly().cmp(b_hex.name_friendly()))
+                .then(b_start.cmp(a_start))
+        });
+
+        let mut timeline: Vec<SchemaDay> = vec![];
+        for (started_at, hex, battle) in grouped {
+            let date = started_at.date();
+            if timeline.last().map(|day| day.date) != Some(date) {
+                timeline.push(SchemaDay::new(war, date));
+            }
+            let day = timeline.last_mut().unwrap();
+            if day.hexes.last().map(|group| group.hex) != Some(hex) {
+                day.hexes.push(SchemaHexBattles::new(hex));
+            }
+            day.hexes.last_mut().unwrap().battles.push(battle);
+        }
+
+        match &mut self.battles {
+            Some(battles) => battles.extend(mapped),
+            None => self.battles = Some(mapped),
+        }
+        self.timeline = Some(timeline);
+        self
+    }
+
+    /// Adds where the battles currently included sit within their list, so the next page can be linked to
+    pub fn page_battles(mut self, page: &Page) -> Self {
+        let battles = self.battles.as_deref().unwrap_or_default();
+        let last = battles.last().map(|battle| battle.cursor);
+        self.page = Some(SchemaPage::new(page, battles.len(), last));
+        self
+    }
+
     /// Populates the `wars` part by all battles currently included
     #[allow(unused_mut)]
     pub async fn wars_from_battles(self, pool: &SqlitePool) -> Result<Self> {
@@ -91,11 +151,26 @@ impl Schema {
         Ok(self.add_wars(wars))
     }
 
+    /// Populates the `regions` part by aggregating the amount of battles in each hex into each region
+    pub fn add_regions(mut self, hex_battles: &HashMap<Hex, usize>) -> Self {
+        trace!("Aggregating battle counts into regions for schema");
+        self.regions = Some(
+            Region::ALL
+                .iter()
+                .map(|region| SchemaRegion::new(*region, hex_battles))
+                .collect(),
+        );
+        self
+    }
+
     /// Converts this schema into a standardized tera context for use in templating
     pub fn to_tmpl_ctx(self) -> tera::Context {
         let mut tmpl_ctx = tera::Context::new();
         tmpl_ctx.insert("battles", &self.battles);
         tmpl_ctx.insert("wars", &self.wars);
+        tmpl_ctx.insert("regions", &self.regions);
+        tmpl_ctx.insert("page", &self.page);
+        tmpl_ctx.insert("timeline", &self.timeline);
         tmpl_ctx
     }
 }
@@ -105,10 +180,88 @@ impl Default for Schema {
         Self {
             wars: None,
             battles: None,
+            regions: None,
+            page: None,
+            timeline: None,
+        }
+    }
+}
+
+/// Day of a war along with the battles which started on it, grouped by hex
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaDay {
+    pub date: NaiveDate,
+    /// Day of the war this was, starting from `1` on the day it broke out
+    pub day_of_war: i64,
+    pub hexes: Vec<SchemaHexBattles>,
+}
+
+impl SchemaDay {
+    /// Creates a new day of a war without any battles yet
+    pub fn new(war: &War, date: NaiveDate) -> Self {
+        Self {
+            date,
+            day_of_war: (date - war.time_start.date()).num_days() + 1,
+            hexes: vec![],
         }
     }
 }
 
+/// Battles which started in a hex, see [SchemaDay]
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaHexBattles {
+    pub hex: Hex,
+    pub name: String,
+    pub battles: Vec<SchemaBattle>,
+}
+
+impl SchemaHexBattles {
+    /// Creates a new hex without any battles yet
+    pub fn new(hex: Hex) -> Self {
+        Self {
+            hex,
+            name: hex.name_friendly().to_string(),
+            battles: vec![],
+        }
+    }
+}
+
+/// Position of a page within a list, along with how to get the next page if there may be one
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaPage {
+    pub page: u32,
+    pub limit: i64,
+    /// Number of the next page if this one was full
+    pub next_page: Option<u32>,
+    /// Cursor to continue after this page with if it was full and the list supports cursors
+    pub after: Option<Cursor>,
+}
+
+impl SchemaPage {
+    /// Creates a new page position from the page asked for, how many items it got and a cursor to the
+    /// last of them if supported
+    pub fn new(page: &Page, count: usize, last: Option<Cursor>) -> Self {
+        let full = page.is_full(count);
+        Self {
+            page: page.number(),
+            limit: page.limit(),
+            next_page: if full { Some(page.number() + 1) } else { None },
+            after: if full { last } else { None },
+        }
+    }
+}
+
+/// Single page of a list for json apis, such as battles
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaList<T: Serialize> {
+    pub items: Vec<T>,
+    pub page: SchemaPage,
+}
+
 /// Conversion for a war model
 #[allow(missing_docs)]
 #[derive(Serialize, Clone)]
@@ -119,17 +272,40 @@ pub struct SchemaWar {
     pub was_today: bool,
     pub time_end: Option<String>,
     pub colonial_win: Option<bool>,
+    /// Faction which won this war if it's over, worked out from `colonial_win`
+    pub victor: Option<Faction>,
+    /// How long this war lasted or has lasted so far, e.g. `3 days, 4 hours`
+    pub duration: String,
+    pub duration_secs: i64,
+    /// Amount of battles fought during this war, if counted, see [SchemaWar::battle_count]
+    pub battle_count: Option<i64>,
+}
+
+impl SchemaWar {
+    /// Adds the amount of battles fought during this war; chainable
+    pub fn battle_count(mut self, count: i64) -> Self {
+        self.battle_count = Some(count);
+        self
+    }
 }
 
 impl From<War> for SchemaWar {
     fn from(war: War) -> Self {
         trace!("Converting war to schema object");
+        let duration = war.duration();
         Self {
             num: war.num,
-            time_start: war.time_start.to_string(),
+            time_start: fmt_iso(war.time_start),
             was_today: war.time_start > last_day_native(),
-            time_end: war.time_end.map(|dt| dt.to_string()),
+            time_end: war.time_end.map(fmt_iso),
             colonial_win: war.colonial_win,
+            victor: war.colonial_win.map(|colonial_win| match colonial_win {
+                true => Faction::Colonial,
+                false => Faction::Warden,
+            }),
+            duration: fmt_duration(duration),
+            duration_secs: duration.num_seconds(),
+            battle_count: None,
         }
     }
 }
@@ -140,17 +316,45 @@ impl From<War> for SchemaWar {
 pub struct SchemaBattle {
     pub id: i64,
     pub war_num: i64,
+    /// Canonical `hex:location` form of this battle's map location
+    pub map: Map,
+    pub region: Region,
     pub location_info: LocationInfo,
     pub name: String,
     pub description: Option<String>,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    /// How long fighting went on for once concluded, e.g. `4 hours, 12 minutes`
+    pub duration: Option<String>,
+    pub status: BattleStatus,
+    pub victor: Option<Faction>,
     pub last_edited: Option<String>,
     pub submitted: String,
     pub pop_reports: Option<Vec<SchemaPopulation>>,
+    /// Ownership changes of this battle's location since it was submitted, if fetched
+    pub captures: Option<Vec<String>>,
+    /// Balance between factions fighting, if known
+    pub factions: Option<SchemaFactions>,
+    /// Every location fought over in order, starting with where fighting began
+    pub front: Vec<SchemaFrontLocation>,
+    /// Cursor pointing to this battle for continuing lists after it, see [crate::page]
+    pub cursor: Cursor,
 }
 
 impl From<Battle> for SchemaBattle {
     fn from(battle: Battle) -> Self {
         trace!("Converting battle to schema object");
+        let captures = battle.ownership.as_ref().map(|_| battle.captures());
+        let cursor = battle.cursor();
+        let duration = battle
+            .ended_at
+            .map(|ended_at| fmt_duration(ended_at - battle.started_at));
+        let factions = battle.factions().map(SchemaFactions::from);
+        let front = battle
+            .front()
+            .into_iter()
+            .map(|(map, at_time)| SchemaFrontLocation::new(map, at_time))
+            .collect();
         let name = match battle.name {
             Some(name) => name,
             None => battle.gen_name(),
@@ -159,12 +363,116 @@ impl From<Battle> for SchemaBattle {
         Self {
             id: battle.id,
             war_num: battle.war_num,
+            map: battle.map,
+            region: battle.map.region(),
             location_info: battle.map.info(),
             name,
             description: battle.description,
-            last_edited: battle.last_edited.map(|dt| dt.to_string()),
-            submitted: battle.submitted.to_string(),
+            started_at: fmt_iso(battle.started_at),
+            ended_at: battle.ended_at.map(fmt_iso),
+            duration,
+            status: battle.status,
+            victor: battle.victor,
+            last_edited: battle.last_edited.map(fmt_iso),
+            submitted: fmt_iso(battle.submitted),
             pop_reports,
+            captures,
+            factions,
+            front,
+            cursor,
+        }
+    }
+}
+
+/// Single location fought over during a battle, see [Battle::front]
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaFrontLocation {
+    /// Canonical `hex:location` form of this location
+    pub map: Map,
+    pub name: String,
+    pub at_time: String,
+}
+
+impl SchemaFrontLocation {
+    /// Converts a location and when fighting reached it
+    pub fn new(map: Map, at_time: NaiveDateTime) -> Self {
+        Self {
+            map,
+            name: map.name_friendly().1.to_string(),
+            at_time: fmt_iso(at_time),
+        }
+    }
+}
+
+/// Conversion for faction counts, with the balance between them worked out for templating
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaFactions {
+    pub colonials: i64,
+    pub wardens: i64,
+    /// Rounded percentage of colonials, adding up to `100` with `warden_percent`
+    pub colonial_percent: i64,
+    /// Rounded percentage of wardens, adding up to `100` with `colonial_percent`
+    pub warden_percent: i64,
+}
+
+impl From<FactionCounts> for SchemaFactions {
+    fn from(factions: FactionCounts) -> Self {
+        Self {
+            colonials: factions.colonials,
+            wardens: factions.wardens,
+            colonial_percent: factions.percent(Faction::Colonial),
+            warden_percent: factions.percent(Faction::Warden),
+        }
+    }
+}
+
+/// Conversion for a single map location, such as those found when searching
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaLocation {
+    /// Canonical `hex:location` form of this location
+    pub map: Map,
+    pub location_info: LocationInfo,
+}
+
+impl From<Map> for SchemaLocation {
+    fn from(map: Map) -> Self {
+        Self {
+            map,
+            location_info: map.info(),
+        }
+    }
+}
+
+/// Aggregated statistics for a single region of the map, see [Schema::add_regions]
+#[allow(missing_docs)]
+#[derive(Serialize, Clone)]
+pub struct SchemaRegion {
+    pub region: Region,
+    pub name: String,
+    pub hexes: Vec<Hex>,
+    /// Amount of battles within this region
+    pub battles: usize,
+    /// Amount of battles within each hex of this region, in the same order as `hexes`
+    pub hex_battles: Vec<usize>,
+}
+
+impl SchemaRegion {
+    /// Aggregates the amount of battles in each hex into the hexes within this region
+    pub fn new(region: Region, hex_battles: &HashMap<Hex, usize>) -> Self {
+        let hexes: Vec<Hex> = region.hexes().collect();
+        let hex_battles: Vec<usize> = hexes
+            .iter()
+            .map(|hex| hex_battles.get(hex).copied().unwrap_or(0))
+            .collect();
+        Self {
+            region,
+            name: region.name_friendly().to_string(),
+            hexes,
+            battles: hex_battles.iter().sum(),
+            hex_battles,
         }
     }
 }
@@ -175,23 +483,36 @@ impl From<Battle> for SchemaBattle {
 pub struct SchemaPopulation {
     pub counted: i64,
     pub at_time: String,
+    pub colonials: Option<i64>,
+    pub wardens: Option<i64>,
     pub description: Option<String>,
     pub last_edited: Option<String>,
     // pub submitted: String, // not needed
+    /// How many were counted compared to the most counted in one report of the same battle, from `0`
+    /// to `100`
+    pub peak_percent: i64,
 }
 
 impl SchemaPopulation {
-    /// Converts optional vector of population reports to a vector of schemas
+    /// Converts optional vector of population reports to a vector of schemas, sized against the
+    /// biggest report of them
     pub fn from_reports(pop_reports: Option<Vec<Population>>) -> Option<Vec<Self>> {
-        match pop_reports {
-            Some(pop_reports) => Some(
-                pop_reports
-                    .into_iter()
-                    .map(|pop_report| SchemaPopulation::from(pop_report))
-                    .collect(),
-            ),
-            None => None,
-        }
+        pop_reports.map(|pop_reports| {
+            let peak = pop_reports.iter().map(|report| report.counted).max();
+            pop_reports
+                .into_iter()
+                .map(|pop_report| SchemaPopulation::from(pop_report).peak(peak.unwrap_or(0)))
+                .collect()
+        })
+    }
+
+    /// Sizes this report against the most counted in one report of its battle; chainable
+    pub fn peak(mut self, peak: i64) -> Self {
+        self.peak_percent = match peak {
+            peak if peak > 0 => (self.counted * 100 / peak).clamp(0, 100),
+            _ => 0,
+        };
+        self
     }
 }
 
@@ -199,9 +520,12 @@ impl From<Population> for SchemaPopulation {
     fn from(pop_report: Population) -> Self {
         Self {
             counted: pop_report.counted,
-            at_time: pop_report.at_time.to_string(),
+            at_time: fmt_iso(pop_report.at_time),
+            colonials: pop_report.colonials,
+            wardens: pop_report.wardens,
             description: pop_report.description,
-            last_edited: pop_report.last_edited.map(|dt| dt.to_string()),
+            last_edited: pop_report.last_edited.map(fmt_iso),
+            peak_percent: 100,
         }
     }
 }
@@ -211,3 +535,95 @@ fn last_day_native() -> NaiveDateTime {
     let utc = Utc::now() - chrono::Duration::from_std(Duration::from_secs(60 * 60 * 24)).unwrap();
     utc.naive_utc()
 }
+
+/// Formats a timestamp as ISO 8601 such as `2021-08-25T14:00:00`, which is how they're parsed back from
+/// paths, queries and json; templates show these with the `date` filter
+fn fmt_iso(dt: NaiveDateTime) -> String {
+    dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+}
+
+/// Formats a duration into its two largest units for reading, e.g. `3 days, 4 hours` or `12 minutes`
+fn fmt_duration(duration: chrono::Duration) -> String {
+    let units = [
+        ("day", duration.num_days()),
+        ("hour", duration.num_hours() % 24),
+        ("minute", duration.num_minutes() % 60),
+    ];
+    let parts: Vec<String> = units
+        .iter()
+        .skip_while(|(_, amount)| *amount <= 0)
+        .take(2)
+        .filter(|(_, amount)| *amount > 0)
+        .map(|(unit, amount)| match amount {
+            1 => format!("1 {}", unit),
+            _ => format!("{} {}s", amount, unit),
+        })
+        .collect();
+    match parts.len() {
+        0 => "Under a minute".to_string(),
+        _ => parts.join(", "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn population_peaks() {
+        let at_time = NaiveDate::from_ymd_opt(2021, 8, 25)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap();
+        let report = |counted| Population {
+            battle_id: 1,
+            counted,
+            at_time,
+            colonials: None,
+            wardens: None,
+            description: None,
+            last_edited: None,
+            submitted: at_time,
+        };
+        let percents: Vec<i64> =
+            SchemaPopulation::from_reports(Some(vec![report(150), report(600), report(0)]))
+                .unwrap()
+                .iter()
+                .map(|schema| schema.peak_percent)
+                .collect();
+        assert_eq!(percents, vec![25, 100, 0]);
+    }
+
+    #[test]
+    fn timestamps_roundtrip() {
+        actix_web::rt::System::new().block_on(async {
+            let pool = crate::models::test_pool().await;
+            let at_time = NaiveDate::from_ymd_opt(2021, 8, 25)
+                .unwrap()
+                .and_hms_milli_opt(14, 0, 0, 250)
+                .unwrap();
+            let war = War::new_historic(&pool, 83, at_time, at_time, true)
+                .await
+                .unwrap();
+            let map = Map::DeadLands(crate::map::DeadLands::ThePits);
+            let battle = Battle::new(&pool, 83, map, at_time, None, None)
+                .await
+                .unwrap();
+
+            // anything given out must be accepted back in json and queries
+            let war = SchemaWar::from(war);
+            let battle = SchemaBattle::from(battle);
+            for time in [
+                war.time_start,
+                war.time_end.unwrap(),
+                battle.started_at,
+                battle.submitted,
+            ] {
+                let parsed: NaiveDateTime =
+                    serde_json::from_value(serde_json::Value::String(time.clone())).unwrap();
+                assert_eq!(fmt_iso(parsed), time);
+                assert_eq!(time.parse::<NaiveDateTime>(), Ok(parsed));
+            }
+        })
+    }
+}
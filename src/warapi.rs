@@ -0,0 +1,368 @@
+//! Typed client for the Foxhole [WarAPI](https://github.com/clapfoot/warapi), see [WarApi]
+//!
+//! The WarAPI gives an `ETag` with each response and asks clients to send it back, only getting a
+//! `304 Not Modified` if nothing has changed. [WarApi] remembers these along with the last response of
+//! each endpoint so unchanged data isn't sent again, and backs off exponentially when the WarAPI is
+//! struggling rather than hammering it further.
+
+use crate::map::{Faction, Hex};
+use crate::{Error, Result};
+use chrono::{DateTime, NaiveDateTime};
+use log::{trace, warn};
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Base url of the live WarAPI, used if none is configured
+pub const DEFAULT_BASE_URL: &str = "https://war-service-live.foxholeservices.com/api";
+
+/// Times a request is retried after failing before giving up
+const RETRIES: u32 = 4;
+
+/// Wait before the first retry, doubling for each one after
+const BACKOFF: Duration = Duration::from_secs(1);
+
+/// State of the current war as given by `/worldconquest/war`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarState {
+    /// Number of this war, matching [crate::models::War::num]
+    pub war_number: i64,
+    /// Winning faction's api name, or `NONE` if it's still being fought
+    pub winner: String,
+    /// Milliseconds since the unix epoch when this war started, if it's begun yet
+    pub conquest_start_time: Option<i64>,
+    /// Milliseconds since the unix epoch when this war ended, if it has
+    pub conquest_end_time: Option<i64>,
+}
+
+impl WarState {
+    /// When this war started, if it has
+    pub fn started(&self) -> Result<Option<NaiveDateTime>> {
+        self.conquest_start_time.map(from_millis).transpose()
+    }
+
+    /// When this war ended and if the colonials won, if it has
+    pub fn ended(&self) -> Result<Option<(NaiveDateTime, bool)>> {
+        let (millis, winner) = match (self.conquest_end_time, Faction::from_name_api(&self.winner))
+        {
+            (Some(millis), Some(winner)) => (millis, winner),
+            _ => return Ok(None),
+        };
+        Ok(Some((from_millis(millis)?, winner == Faction::Colonial)))
+    }
+}
+
+/// Casualty counters of a hex for the current war as given by `/worldconquest/warReport/{hex}`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WarReport {
+    /// Amount of players who have enlisted here
+    pub total_enlistments: i64,
+    /// Total colonial casualties so far
+    pub colonial_casualties: i64,
+    /// Total warden casualties so far
+    pub warden_casualties: i64,
+    /// Day of the war which this report was made in
+    pub day_of_war: i64,
+}
+
+impl WarReport {
+    /// Total casualties of both factions so far
+    pub fn casualties(&self) -> i64 {
+        self.colonial_casualties + self.warden_casualties
+    }
+}
+
+/// Live structures within a hex as given by `/worldconquest/maps/{hex}/dynamic/public`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicMap {
+    /// Every structure which is shown on the map
+    pub map_items: Vec<MapItem>,
+}
+
+/// Single structure on the map, such as a town hall or watch tower
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MapItem {
+    /// Api name of the faction holding this, or `NONE`
+    pub team_id: String,
+    /// Kind of structure, see the WarAPI documentation for all of them
+    pub icon_type: i64,
+    /// In-hex `x` coordinate from `0` to `1`, like [crate::map::Location::coords]
+    pub x: f64,
+    /// In-hex `y` coordinate from `0` to `1`, like [crate::map::Location::coords]
+    pub y: f64,
+    /// Bitflags of this structure, such as if it's a victory base
+    pub flags: i64,
+}
+
+/// Last response of an endpoint along with its `ETag`
+struct Cached {
+    etag: String,
+    body: String,
+}
+
+/// Client for the WarAPI which caches responses by their `ETag` and retries with backoff
+pub struct WarApi {
+    client: reqwest::Client,
+    base_url: String,
+    retries: u32,
+    backoff: Duration,
+    cache: Mutex<HashMap<String, Cached>>,
+}
+
+impl WarApi {
+    /// Creates a new client for the WarAPI at `base_url`, such as [DEFAULT_BASE_URL]
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            retries: RETRIES,
+            backoff: BACKOFF,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how many times a failed request is retried; chainable
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the wait before the first retry, which doubles for each retry after; chainable
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Base url this client requests from
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Gets the state of the current war
+    pub async fn war(&self) -> Result<WarState> {
+        self.fetch("/worldconquest/war").await
+    }
+
+    /// Gets the live structures within a hex
+    pub async fn dynamic_map(&self, hex: Hex) -> Result<DynamicMap> {
+        self.fetch(&format!(
+            "/worldconquest/maps/{}/dynamic/public",
+            hex.name_pos()
+        ))
+        .await
+    }
+
+    /// Gets the casualty counters of a hex
+    pub async fn war_report(&self, hex: Hex) -> Result<WarReport> {
+        self.fetch(&format!("/worldconquest/warReport/{}", hex.name_pos()))
+            .await
+    }
+
+    /// Fetches and decodes json from an endpoint, retrying with backoff if the WarAPI is struggling
+    async fn fetch<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let mut attempt = 0;
+        let body = loop {
+            match self.fetch_body(endpoint).await {
+                Ok(body) => break body,
+                Err(err) if attempt < self.retries && is_retryable(&err) => {
+                    let wait = backoff_wait(self.backoff, attempt);
+                    warn!(
+                        "Retrying WarAPI endpoint {} in {:?}, {}",
+                        endpoint, wait, err
+                    );
+                    tokio::time::sleep(wait).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        };
+        serde_json::from_str(&body).map_err(Error::WarApiData)
+    }
+
+    /// Fetches the body of an endpoint, sending the last `ETag` of it and using the cached body if
+    /// nothing has changed
+    async fn fetch_body(&self, endpoint: &str) -> reqwest::Result<String> {
+        let mut request = self.client.get(format!("{}{}", self.base_url, endpoint));
+        if let Some(cached) = self.cache.lock().unwrap().get(endpoint) {
+            request = request.header(IF_NONE_MATCH, cached.etag.as_str());
+        }
+
+        let resp = request.send().await?.error_for_status()?;
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = self.cache.lock().unwrap().get(endpoint) {
+                trace!("WarAPI endpoint {} not modified", endpoint);
+                return Ok(cached.body.clone());
+            }
+        }
+
+        let etag = resp
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        let body = resp.text().await?;
+        if let Some(etag) = etag {
+            self.cache.lock().unwrap().insert(
+                endpoint.to_string(),
+                Cached {
+                    etag,
+                    body: body.clone(),
+                },
+            );
+        }
+        Ok(body)
+    }
+}
+
+/// Indicates if a failed request may work if tried again, such as when timing out or the WarAPI being
+/// overloaded, instead of never working like a hex which doesn't exist
+fn is_retryable(err: &reqwest::Error) -> bool {
+    match err.status() {
+        Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+        None => err.is_timeout() || err.is_connect(),
+    }
+}
+
+/// Wait before a retry, doubling from `backoff` for each attempt already made
+fn backoff_wait(backoff: Duration, attempt: u32) -> Duration {
+    backoff * 2u32.saturating_pow(attempt)
+}
+
+/// Converts milliseconds since the unix epoch as used by the WarAPI into a timestamp, erroring if it's
+/// too far out to be one
+pub fn from_millis(millis: i64) -> Result<NaiveDateTime> {
+    DateTime::from_timestamp_millis(millis)
+        .map(|dt| dt.naive_utc())
+        .ok_or_else(|| {
+            Error::WarApiData(serde::de::Error::custom(format!(
+                "timestamp of {}ms is out of range",
+                millis
+            )))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+    use chrono::NaiveDate;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn war_state() {
+        let ongoing: WarState = serde_json::from_str(
+            r#"{"warId":"abc","warNumber":83,"winner":"NONE","conquestStartTime":1629900000000,"conquestEndTime":null,"resistanceStartTime":null,"requiredVictoryTowns":32}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ongoing.started().unwrap(),
+            Some(
+                NaiveDate::from_ymd_opt(2021, 8, 25)
+                    .unwrap()
+                    .and_hms_opt(14, 0, 0)
+                    .unwrap()
+            )
+        );
+        assert_eq!(ongoing.ended().unwrap(), None);
+
+        let ended = WarState {
+            winner: "WARDENS".to_string(),
+            conquest_end_time: Some(1_630_000_000_500),
+            ..ongoing
+        };
+        assert_eq!(
+            ended.ended().unwrap(),
+            Some((
+                NaiveDate::from_ymd_opt(2021, 8, 26)
+                    .unwrap()
+                    .and_hms_milli_opt(17, 46, 40, 500)
+                    .unwrap(),
+                false
+            ))
+        );
+
+        let broken = WarState {
+            conquest_start_time: Some(i64::MAX),
+            ..ended
+        };
+        assert!(matches!(broken.started(), Err(Error::WarApiData(_))));
+    }
+
+    #[test]
+    fn war_report() {
+        let report: WarReport = serde_json::from_str(
+            r#"{"totalEnlistments":1200,"colonialCasualties":340,"wardenCasualties":410,"dayOfWar":3,"version":18}"#,
+        )
+        .unwrap();
+        assert_eq!(report.casualties(), 750);
+    }
+
+    #[test]
+    fn backoff_doubles() {
+        let waits: Vec<u64> = (0..4)
+            .map(|attempt| backoff_wait(BACKOFF, attempt).as_secs())
+            .collect();
+        assert_eq!(waits, vec![1, 2, 4, 8]);
+    }
+
+    /// Serves a single war endpoint on a free port which responds with `status`, giving the base url
+    /// and how many requests it's had
+    fn spawn_war(status: u16) -> (String, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let server = HttpServer::new(move || {
+            let counter = counter.clone();
+            App::new().route(
+                "/worldconquest/war",
+                web::get().to(move |req: HttpRequest| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let mut resp = match req.headers().get("if-none-match") {
+                        Some(_) => HttpResponse::NotModified(),
+                        None => HttpResponse::build(
+                            actix_web::http::StatusCode::from_u16(status).unwrap(),
+                        ),
+                    };
+                    let body = r#"{"warNumber":83,"winner":"NONE","conquestStartTime":1629900000000,"conquestEndTime":null}"#;
+                    async move { resp.insert_header(("etag", "\"83\"")).body(body) }
+                }),
+            )
+        })
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let base_url = format!("http://{}", server.addrs()[0]);
+        actix_web::rt::spawn(server.run());
+        (base_url, hits)
+    }
+
+    #[test]
+    fn not_modified_uses_cache() {
+        actix_web::rt::System::new().block_on(async {
+            let (base_url, hits) = spawn_war(200);
+            let api = WarApi::new(base_url);
+            let first = api.war().await.unwrap();
+            assert_eq!(api.war().await.unwrap(), first);
+            assert_eq!(hits.load(Ordering::SeqCst), 2);
+        })
+    }
+
+    #[test]
+    fn retries_give_up() {
+        actix_web::rt::System::new().block_on(async {
+            let (base_url, hits) = spawn_war(503);
+            let api = WarApi::new(base_url)
+                .retries(2)
+                .backoff(Duration::from_millis(1));
+            assert!(matches!(api.war().await, Err(Error::WarApi(_))));
+            assert_eq!(hits.load(Ordering::SeqCst), 3);
+        })
+    }
+}